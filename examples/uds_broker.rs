@@ -0,0 +1,27 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use up_rust::uds_transport::UdsBroker;
+
+const DEFAULT_SOCKET_PATH: &str = "/tmp/uprotocol-broker.sock";
+
+#[tokio::main]
+pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_SOCKET_PATH.to_string());
+    let broker = UdsBroker::bind(&path)?;
+    println!("broker listening on {}", broker.path().display());
+    broker.run().await?;
+    Ok(())
+}
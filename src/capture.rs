@@ -0,0 +1,742 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides means for capturing the messages exchanged via a [`UTransport`] and for replaying them later on.
+
+A [`TrafficRecorder`] registers a listener for all messages with a transport and writes each
+received message to a _capture_, along with the time at which the message has been received.
+The messages contained in a capture can then be sent again via any transport, e.g. a
+[`LocalTransport`](crate::local_transport::LocalTransport), using [`replay`].
+
+A capture consists of a sequence of records, each of which is written as a length-delimited protobuf
+encoding of the following message:
+
+```proto
+message CaptureRecord {
+  // the time at which the message has been received, in microseconds since the UNIX epoch
+  uint64 timestamp = 1;
+  uprotocol.v1.UMessage message = 2;
+}
+```
+*/
+
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use protobuf::{rt::WireType, CodedInputStream, CodedOutputStream};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::debug;
+
+use crate::{UCode, UListener, UMessage, UStatus, UTransport, UUri};
+
+const FIELD_TIMESTAMP: u32 = 1;
+const FIELD_MESSAGE: u32 = 2;
+/// The maximum size of a record that is being accepted.
+const MAX_RECORD_SIZE: usize = 64 * 1024 * 1024;
+
+/// A message that has been captured.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CaptureRecord {
+    /// The time at which the message has been received.
+    pub timestamp: SystemTime,
+    /// The message.
+    pub message: UMessage,
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+impl CaptureRecord {
+    fn encode(&self) -> io::Result<Vec<u8>> {
+        let timestamp = self
+            .timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?
+            .as_micros() as u64;
+        let mut body = Vec::new();
+        let mut os = CodedOutputStream::vec(&mut body);
+        os.write_uint64(FIELD_TIMESTAMP, timestamp)?;
+        os.write_message(FIELD_MESSAGE, &self.message)?;
+        os.flush()?;
+        drop(os);
+
+        let mut bytes = Vec::with_capacity(body.len() + 5);
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        os.write_raw_varint32(body.len() as u32)?;
+        os.write_raw_bytes(&body)?;
+        os.flush()?;
+        drop(os);
+        Ok(bytes)
+    }
+
+    fn decode(body: &[u8]) -> io::Result<Self> {
+        let mut is = CodedInputStream::from_bytes(body);
+        let mut timestamp = None;
+        let mut message = None;
+        while let Some(tag) = is.read_raw_tag_or_eof()? {
+            let field_number = tag >> 3;
+            let wire_type = WireType::new(tag & 0x07).ok_or_else(|| invalid_data("invalid tag"))?;
+            match (field_number, wire_type) {
+                (FIELD_TIMESTAMP, WireType::Varint) => timestamp = Some(is.read_uint64()?),
+                (FIELD_MESSAGE, WireType::LengthDelimited) => message = Some(is.read_message()?),
+                _ => is.skip_field(wire_type)?,
+            }
+        }
+        match (timestamp, message) {
+            (Some(timestamp), Some(message)) => Ok(CaptureRecord {
+                timestamp: UNIX_EPOCH + Duration::from_micros(timestamp),
+                message,
+            }),
+            _ => Err(invalid_data("record lacks timestamp or message")),
+        }
+    }
+}
+
+/// Writes [`CaptureRecord`]s to a byte sink.
+pub struct CaptureWriter<W: Write> {
+    writer: W,
+}
+
+impl CaptureWriter<BufWriter<File>> {
+    /// Creates a writer for a new capture file.
+    ///
+    /// An existing file is truncated.
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        File::create(path).map(|file| CaptureWriter::new(BufWriter::new(file)))
+    }
+}
+
+impl<W: Write> CaptureWriter<W> {
+    /// Creates a writer for a byte sink.
+    pub fn new(writer: W) -> Self {
+        CaptureWriter { writer }
+    }
+
+    /// Writes a record.
+    pub fn write(&mut self, record: &CaptureRecord) -> io::Result<()> {
+        let bytes = record.encode()?;
+        self.writer.write_all(&bytes)
+    }
+
+    /// Flushes all buffered records to the underlying byte sink.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Gets the underlying byte sink.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+/// Reads [`CaptureRecord`]s from a byte source.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::SystemTime;
+/// use up_rust::{
+///     capture::{CaptureReader, CaptureRecord, CaptureWriter},
+///     UMessageBuilder, UUri,
+/// };
+///
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let record = CaptureRecord {
+///     timestamp: SystemTime::now(),
+///     message: UMessageBuilder::publish(topic).build().unwrap(),
+/// };
+/// let mut writer = CaptureWriter::new(Vec::new());
+/// writer.write(&record).unwrap();
+///
+/// let bytes = writer.into_inner();
+/// let mut reader = CaptureReader::new(bytes.as_slice());
+/// assert_eq!(reader.next().unwrap().unwrap().message, record.message);
+/// assert!(reader.next().is_none());
+/// ```
+pub struct CaptureReader<R: Read> {
+    reader: R,
+}
+
+impl CaptureReader<BufReader<File>> {
+    /// Creates a reader for an existing capture file.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        File::open(path).map(|file| CaptureReader::new(BufReader::new(file)))
+    }
+}
+
+impl<R: Read> CaptureReader<R> {
+    /// Creates a reader for a byte source.
+    pub fn new(reader: R) -> Self {
+        CaptureReader { reader }
+    }
+
+    /// Reads the varint encoded length of the next record.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the end of the capture has been reached.
+    fn read_length(&mut self) -> io::Result<Option<usize>> {
+        let mut length = 0_usize;
+        for shift in (0..35).step_by(7) {
+            let mut byte = [0_u8; 1];
+            match self.reader.read_exact(&mut byte) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && shift == 0 => {
+                    return Ok(None)
+                }
+                Err(e) => return Err(e),
+            }
+            length |= ((byte[0] & 0x7f) as usize) << shift;
+            if byte[0] & 0x80 == 0 {
+                return Ok(Some(length));
+            }
+        }
+        Err(invalid_data("invalid record length"))
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<CaptureRecord>> {
+        let Some(length) = self.read_length()? else {
+            return Ok(None);
+        };
+        if length > MAX_RECORD_SIZE {
+            return Err(invalid_data(format!(
+                "record length [{length}] exceeds limit"
+            )));
+        }
+        let mut body = vec![0_u8; length];
+        self.reader.read_exact(&mut body)?;
+        CaptureRecord::decode(&body).map(Some)
+    }
+}
+
+impl<R: Read> Iterator for CaptureReader<R> {
+    type Item = io::Result<CaptureRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_record().transpose()
+    }
+}
+
+/// A listener that forwards all messages to the task writing the capture.
+struct RecordingListener {
+    // the sender is dropped once recording has been stopped, which ends the writer task
+    records: Mutex<Option<mpsc::UnboundedSender<CaptureRecord>>>,
+}
+
+impl RecordingListener {
+    fn close(&self) {
+        if let Ok(mut records) = self.records.lock() {
+            records.take();
+        }
+    }
+}
+
+#[async_trait]
+impl UListener for RecordingListener {
+    async fn on_receive(&self, msg: UMessage) {
+        let record = CaptureRecord {
+            timestamp: crate::clock::now(),
+            message: msg,
+        };
+        let sent = self
+            .records
+            .lock()
+            .is_ok_and(|records| records.as_ref().is_some_and(|tx| tx.send(record).is_ok()));
+        if !sent {
+            debug!("recording has been stopped, discarding message");
+        }
+    }
+}
+
+/// Writes all records received from a channel to a capture, until the channel is closed.
+fn write_records<W: Write>(
+    mut records: mpsc::UnboundedReceiver<CaptureRecord>,
+    writer: W,
+) -> io::Result<()> {
+    let mut writer = CaptureWriter::new(writer);
+    while let Some(record) = records.blocking_recv() {
+        if let Err(e) = writer.write(&record) {
+            debug!("failed to write message to capture: {e}");
+        }
+    }
+    writer.flush()
+}
+
+/// Records all messages received via a transport.
+///
+/// Messages are written to the capture by a blocking task, so that listeners of the transport
+/// are not delayed by writing to the capture. The time at which a message has been received is
+/// determined by the same clock that is used for creating message IDs.
+///
+/// Dropping the recorder without [stopping](Self::stop) it unregisters its listener from the
+/// transport in the background. Messages that have been received up to then are still written
+/// to the capture.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::sync::Arc;
+/// use up_rust::{capture::TrafficRecorder, local_transport::LocalTransport};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = Arc::new(LocalTransport::default());
+/// let recorder = TrafficRecorder::start_to_file(transport, "trace.bin").await.unwrap();
+/// // exchange messages via the transport
+/// recorder.stop().await.unwrap();
+/// # }
+/// ```
+pub struct TrafficRecorder {
+    transport: Arc<dyn UTransport>,
+    listener: Arc<RecordingListener>,
+    writer_task: Option<JoinHandle<io::Result<()>>>,
+}
+
+impl TrafficRecorder {
+    /// The filters that the recorder registers its listener for, matching all messages.
+    fn filters() -> [(UUri, Option<UUri>); 2] {
+        let any = UUri::any();
+        [(any.clone(), None), (any.clone(), Some(any))]
+    }
+
+    /// Starts recording all messages received via a transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to record messages from.
+    /// * `writer` - The byte sink to write the capture to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener for recording messages cannot be registered with the transport.
+    /// In this case, the listener is not registered for any filters.
+    pub async fn start<W: Write + Send + 'static>(
+        transport: Arc<dyn UTransport>,
+        writer: W,
+    ) -> Result<Self, UStatus> {
+        let (records_tx, records_rx) = mpsc::unbounded_channel();
+        let listener = Arc::new(RecordingListener {
+            records: Mutex::new(Some(records_tx)),
+        });
+        let filters = Self::filters();
+        for (idx, (source_filter, sink_filter)) in filters.iter().enumerate() {
+            if let Err(e) = transport
+                .register_listener(source_filter, sink_filter.as_ref(), listener.clone())
+                .await
+            {
+                // roll back the registrations that have succeeded so far
+                for (registered_source_filter, registered_sink_filter) in &filters[..idx] {
+                    let _ = transport
+                        .unregister_listener(
+                            registered_source_filter,
+                            registered_sink_filter.as_ref(),
+                            listener.clone(),
+                        )
+                        .await;
+                }
+                return Err(e);
+            }
+        }
+        let writer_task = tokio::task::spawn_blocking(move || write_records(records_rx, writer));
+        Ok(TrafficRecorder {
+            transport,
+            listener,
+            writer_task: Some(writer_task),
+        })
+    }
+
+    /// Starts recording all messages received via a transport to a new capture file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`] if the file cannot be created or an error
+    /// if the listener for recording messages cannot be registered with the transport.
+    pub async fn start_to_file<P: AsRef<Path>>(
+        transport: Arc<dyn UTransport>,
+        path: P,
+    ) -> Result<Self, UStatus> {
+        let file = File::create(path)
+            .map_err(|e| UStatus::fail_with_code(UCode::UNAVAILABLE, e.to_string()))?;
+        Self::start(transport, BufWriter::new(file)).await
+    }
+
+    /// Stops recording messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be unregistered from the transport or if the
+    /// capture cannot be flushed.
+    pub async fn stop(mut self) -> Result<(), UStatus> {
+        for (source_filter, sink_filter) in Self::filters() {
+            self.transport
+                .unregister_listener(&source_filter, sink_filter.as_ref(), self.listener.clone())
+                .await?;
+        }
+        self.listener.close();
+        let Some(writer_task) = self.writer_task.take() else {
+            return Ok(());
+        };
+        writer_task
+            .await
+            .map_err(|e| UStatus::fail_with_code(UCode::INTERNAL, e.to_string()))?
+            .map_err(|e| UStatus::fail_with_code(UCode::UNAVAILABLE, e.to_string()))
+    }
+}
+
+impl Drop for TrafficRecorder {
+    fn drop(&mut self) {
+        self.listener.close();
+        if self.writer_task.is_none() {
+            // the recorder has been stopped already
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            debug!("no runtime available for unregistering recording listener");
+            return;
+        };
+        let transport = self.transport.clone();
+        let listener = self.listener.clone();
+        runtime.spawn(async move {
+            for (source_filter, sink_filter) in TrafficRecorder::filters() {
+                if let Err(e) = transport
+                    .unregister_listener(&source_filter, sink_filter.as_ref(), listener.clone())
+                    .await
+                {
+                    debug!("failed to unregister recording listener: {e}");
+                }
+            }
+        });
+    }
+}
+
+/// The speed at which captured messages are being replayed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ReplaySpeed {
+    /// Messages are sent with the same time gaps in between them as when they had been recorded.
+    #[default]
+    Original,
+    /// The time gaps between messages are divided by the given factor, i.e. a factor of 2.0 replays
+    /// messages twice as fast as they had been recorded.
+    ///
+    /// The factor must be greater than 0.0, otherwise [`replay`] fails.
+    Scaled(f64),
+    /// Messages are sent right after each other.
+    Unthrottled,
+}
+
+impl ReplaySpeed {
+    fn scale(&self, gap: Duration) -> Option<Duration> {
+        match self {
+            ReplaySpeed::Original => Some(gap),
+            ReplaySpeed::Scaled(factor) => {
+                Duration::try_from_secs_f64(gap.as_secs_f64() / factor).ok()
+            }
+            ReplaySpeed::Unthrottled => None,
+        }
+    }
+}
+
+/// Sends all messages contained in a capture via a transport.
+///
+/// Messages that cannot be sent are skipped.
+///
+/// # Arguments
+///
+/// * `records` - The records to replay, e.g. a [`CaptureReader`].
+/// * `transport` - The transport to send the messages with.
+/// * `speed` - The speed at which the messages are replayed.
+///
+/// # Returns
+///
+/// The number of messages that have been sent successfully.
+///
+/// # Errors
+///
+/// Returns an error with
+/// * [`UCode::INVALID_ARGUMENT`] if the speed is [scaled](ReplaySpeed::Scaled) by a factor that is not
+///   greater than 0.0,
+/// * [`UCode::DATA_LOSS`] if the records cannot be read.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{sync::Arc, time::{Duration, SystemTime}};
+/// use up_rust::{
+///     capture::{replay, CaptureRecord, ReplaySpeed},
+///     local_transport::LocalTransport,
+///     UMessageBuilder, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let start = SystemTime::now();
+/// let records = (0..3).map(|i| Ok(CaptureRecord {
+///     timestamp: start + Duration::from_millis(i * 100),
+///     message: UMessageBuilder::publish(topic.clone()).build().unwrap(),
+/// }));
+/// let transport = LocalTransport::default();
+/// // takes 20ms instead of 200ms
+/// let replayed = replay(records, &transport, ReplaySpeed::Scaled(10.0)).await.unwrap();
+/// assert_eq!(replayed, 3);
+/// # }
+/// ```
+pub async fn replay<I>(
+    records: I,
+    transport: &dyn UTransport,
+    speed: ReplaySpeed,
+) -> Result<usize, UStatus>
+where
+    I: IntoIterator<Item = io::Result<CaptureRecord>>,
+{
+    if let ReplaySpeed::Scaled(factor) = speed {
+        if factor.is_nan() || factor <= 0.0 {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                format!("replay speed factor [{factor}] must be greater than 0.0"),
+            ));
+        }
+    }
+    let mut first_timestamp = None;
+    let start = tokio::time::Instant::now();
+    let mut replayed = 0;
+    for record in records {
+        let record =
+            record.map_err(|e| UStatus::fail_with_code(UCode::DATA_LOSS, e.to_string()))?;
+        let first_timestamp = *first_timestamp.get_or_insert(record.timestamp);
+        let gap = record
+            .timestamp
+            .duration_since(first_timestamp)
+            .unwrap_or_default();
+        if let Some(delay) = speed.scale(gap) {
+            tokio::time::sleep_until(start + delay).await;
+        }
+        match transport.send(record.message).await {
+            Ok(()) => replayed += 1,
+            Err(e) => debug!("failed to replay message: {e}"),
+        }
+    }
+    Ok(replayed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    use crate::{
+        local_transport::LocalTransport, utransport::MockTransport, UMessageBuilder, UPayloadFormat,
+    };
+
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_reader_fails_for_truncated_capture() {
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let record = CaptureRecord {
+            timestamp: SystemTime::now(),
+            message: UMessageBuilder::publish(topic).build().unwrap(),
+        };
+        let mut writer = CaptureWriter::new(Vec::new());
+        writer.write(&record).unwrap();
+        let bytes = writer.into_inner();
+
+        let mut reader = CaptureReader::new(&bytes[..bytes.len() - 1]);
+        assert!(reader.next().is_some_and(|result| result.is_err()));
+    }
+
+    #[tokio::test]
+    async fn test_start_rolls_back_registrations_on_failure() {
+        let mut transport = MockTransport::new();
+        transport
+            .expect_do_register_listener()
+            .withf(|_source_filter, sink_filter, _listener| sink_filter.is_none())
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        transport
+            .expect_do_register_listener()
+            .withf(|_source_filter, sink_filter, _listener| sink_filter.is_some())
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| {
+                Err(UStatus::fail_with_code(
+                    UCode::RESOURCE_EXHAUSTED,
+                    "too many listeners",
+                ))
+            });
+        transport
+            .expect_do_unregister_listener()
+            .withf(|source_filter, sink_filter, _listener| {
+                source_filter == &UUri::any() && sink_filter.is_none()
+            })
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+
+        let result = TrafficRecorder::start(Arc::new(transport), Vec::new()).await;
+
+        assert!(result.is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+    }
+
+    #[tokio::test]
+    async fn test_recorded_messages_can_be_replayed() {
+        // GIVEN a recorder for a transport
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let transport = Arc::new(LocalTransport::default());
+        let recorder = TrafficRecorder::start(transport.clone(), SharedBuffer(buffer.clone()))
+            .await
+            .unwrap();
+
+        // WHEN messages are sent via the transport
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let publish = UMessageBuilder::publish(topic)
+            .build_with_payload("hello", UPayloadFormat::UPAYLOAD_FORMAT_TEXT)
+            .unwrap();
+        let notification = UMessageBuilder::notification(
+            UUri::try_from("//my-vehicle/A100/1/8002").unwrap(),
+            UUri::try_from("//my-vehicle/B200/1/0").unwrap(),
+        )
+        .build()
+        .unwrap();
+        transport.send(publish.clone()).await.unwrap();
+        transport.send(notification.clone()).await.unwrap();
+        recorder.stop().await.unwrap();
+
+        // THEN the captured messages can be replayed into another transport
+        let capture = buffer.lock().unwrap().clone();
+        let records: Vec<CaptureRecord> = CaptureReader::new(capture.as_slice())
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert!(records[0].timestamp <= records[1].timestamp);
+
+        let target = LocalTransport::default();
+        let target_buffer = Arc::new(Mutex::new(Vec::new()));
+        let target_transport = Arc::new(target);
+        let target_recorder = TrafficRecorder::start(
+            target_transport.clone(),
+            SharedBuffer(target_buffer.clone()),
+        )
+        .await
+        .unwrap();
+        let replayed = replay(
+            records.into_iter().map(Ok),
+            target_transport.as_ref(),
+            ReplaySpeed::Unthrottled,
+        )
+        .await
+        .unwrap();
+        target_recorder.stop().await.unwrap();
+        assert_eq!(replayed, 2);
+
+        let replayed_messages: Vec<UMessage> =
+            CaptureReader::new(target_buffer.lock().unwrap().as_slice())
+                .map(|record| record.unwrap().message)
+                .collect();
+        assert_eq!(replayed_messages, vec![publish, notification]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_recorder_uses_virtual_clock() {
+        let start_time = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        crate::clock::install_virtual_clock(start_time);
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let transport = Arc::new(LocalTransport::default());
+        let recorder = TrafficRecorder::start(transport.clone(), SharedBuffer(buffer.clone()))
+            .await
+            .unwrap();
+
+        tokio::time::advance(Duration::from_secs(5)).await;
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        transport
+            .send(UMessageBuilder::publish(topic).build().unwrap())
+            .await
+            .unwrap();
+        recorder.stop().await.unwrap();
+        crate::clock::uninstall_virtual_clock();
+
+        let record = CaptureReader::new(buffer.lock().unwrap().as_slice())
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.timestamp, start_time + Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_dropping_recorder_unregisters_listener() {
+        let transport = Arc::new(LocalTransport::default());
+        let recorder = TrafficRecorder::start(transport.clone(), Vec::new())
+            .await
+            .unwrap();
+        assert_eq!(transport.statistics().unwrap().total_listener_count(), 2);
+
+        drop(recorder);
+
+        let unregistered = tokio::time::timeout(Duration::from_secs(2), async {
+            while transport.statistics().unwrap().total_listener_count() > 0 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await;
+        assert!(unregistered.is_ok());
+    }
+
+    #[test_case(0.0; "for zero")]
+    #[test_case(-1.0; "for negative factor")]
+    #[test_case(f64::NAN; "for NaN")]
+    #[tokio::test]
+    async fn test_replay_rejects_invalid_speed_factor(factor: f64) {
+        let transport = LocalTransport::default();
+        assert!(replay(vec![], &transport, ReplaySpeed::Scaled(factor))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_replay_preserves_timing() {
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let start = SystemTime::now();
+        let records: Vec<io::Result<CaptureRecord>> = [0, 1000, 3000]
+            .into_iter()
+            .map(|offset| {
+                Ok(CaptureRecord {
+                    timestamp: start + Duration::from_millis(offset),
+                    message: UMessageBuilder::publish(topic.clone()).build().unwrap(),
+                })
+            })
+            .collect();
+        let transport = LocalTransport::default();
+
+        let before = tokio::time::Instant::now();
+        replay(records, &transport, ReplaySpeed::Scaled(2.0))
+            .await
+            .unwrap();
+        assert_eq!(before.elapsed(), Duration::from_millis(1500));
+    }
+}
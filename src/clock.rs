@@ -0,0 +1,54 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides the current time to the functions that create message IDs and check message expiry.
+
+For testing purposes, the system clock can be replaced with a virtual clock for the current thread.
+The virtual clock is driven by tokio's (pausable) time, which allows tests to advance time deterministically.
+*/
+
+use std::time::SystemTime;
+
+#[cfg(all(any(test, feature = "test-util"), feature = "util"))]
+thread_local! {
+    // the system time and tokio instant at which the virtual clock has been installed
+    static VIRTUAL_CLOCK: std::cell::Cell<Option<(SystemTime, tokio::time::Instant)>> =
+        const { std::cell::Cell::new(None) };
+}
+
+/// Gets the current time.
+///
+/// This is the time of the virtual clock, if one has been installed for the current thread,
+/// or the system time otherwise.
+pub(crate) fn now() -> SystemTime {
+    #[cfg(all(any(test, feature = "test-util"), feature = "util"))]
+    if let Some((start_time, start_instant)) = VIRTUAL_CLOCK.with(|clock| clock.get()) {
+        return start_time + tokio::time::Instant::now().duration_since(start_instant);
+    }
+    SystemTime::now()
+}
+
+/// Replaces the system clock with a virtual clock for the current thread.
+///
+/// The virtual clock starts at the given time and advances along with tokio's time.
+#[cfg(all(any(test, feature = "test-util"), feature = "util"))]
+pub(crate) fn install_virtual_clock(start_time: SystemTime) {
+    VIRTUAL_CLOCK.with(|clock| clock.set(Some((start_time, tokio::time::Instant::now()))));
+}
+
+/// Restores the system clock for the current thread.
+#[cfg(all(any(test, feature = "test-util"), feature = "util"))]
+pub(crate) fn uninstall_virtual_clock() {
+    VIRTUAL_CLOCK.with(|clock| clock.set(None));
+}
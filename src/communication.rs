@@ -18,6 +18,8 @@ use std::{error::Error, fmt::Display};
 pub use default_notifier::SimpleNotifier;
 #[cfg(feature = "usubscription")]
 pub use default_pubsub::{InMemorySubscriber, SimplePublisher};
+#[cfg(any(test, feature = "test-util"))]
+pub use fake_rpc_service::{FakeRpcService, FakeRpcServiceBuilder};
 pub use in_memory_rpc_client::InMemoryRpcClient;
 pub use in_memory_rpc_server::InMemoryRpcServer;
 #[cfg(any(test, feature = "test-util"))]
@@ -37,11 +39,14 @@ pub use usubscription_client::RpcClientUSubscription;
 
 use crate::{
     umessage::{self, UMessageError},
-    UCode, UMessage, UMessageBuilder, UPayloadFormat, UPriority, UStatus, UUID,
+    UCode, UMessage, UMessageBuilder, UMessageType, UPayloadFormat, UPriority, UStatus, UTransport,
+    UUID,
 };
 
 mod default_notifier;
 mod default_pubsub;
+#[cfg(any(test, feature = "test-util"))]
+mod fake_rpc_service;
 mod in_memory_rpc_client;
 mod in_memory_rpc_server;
 mod notification;
@@ -113,6 +118,41 @@ impl From<UStatus> for RegistrationError {
     }
 }
 
+/// Verifies that a transport has not declared any of the features required by a client to be missing.
+///
+/// # Arguments
+///
+/// * `transport` - The transport to check.
+/// * `message_types` - The types of messages that the client needs to exchange.
+///
+/// # Errors
+///
+/// Returns an error if the transport does not support the push delivery method. Returns
+/// [`RegistrationError::Unknown`] with a status having code [`UCode::UNIMPLEMENTED`] if the transport does
+/// not support any of the message types.
+pub(crate) fn verify_transport_capabilities(
+    transport: &dyn UTransport,
+    message_types: &[UMessageType],
+) -> Result<(), RegistrationError> {
+    let capabilities = transport.capabilities();
+    if capabilities.supports_push_delivery() == Some(false) {
+        return Err(RegistrationError::PushDeliveryMethodNotSupported);
+    }
+    if let Some(unsupported_type) = message_types
+        .iter()
+        .find(|message_type| capabilities.supports_message_type(**message_type) == Some(false))
+    {
+        return Err(RegistrationError::Unknown(UStatus::fail_with_code(
+            UCode::UNIMPLEMENTED,
+            format!(
+                "the underlying transport implementation does not support {:?} messages",
+                unsupported_type
+            ),
+        )));
+    }
+    Ok(())
+}
+
 /// General options that clients might want to specify when sending a uProtocol message.
 #[derive(Clone, Debug, PartialEq)]
 pub struct CallOptions {
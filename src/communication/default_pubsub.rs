@@ -26,13 +26,14 @@ use crate::{
     core::usubscription::{
         self, State, SubscriptionRequest, USubscription, UnsubscribeRequest, Update,
     },
-    LocalUriProvider, UListener, UMessage, UMessageBuilder, UStatus, UTransport, UUri,
+    LocalUriProvider, UListener, UMessage, UMessageBuilder, UMessageType, UStatus, UTransport,
+    UUri,
 };
 
 use super::{
-    apply_common_options, build_message, pubsub::SubscriptionChangeHandler, CallOptions,
-    InMemoryRpcClient, Notifier, PubSubError, Publisher, RegistrationError, RpcClientUSubscription,
-    SimpleNotifier, Subscriber, UPayload,
+    apply_common_options, build_message, pubsub::SubscriptionChangeHandler,
+    verify_transport_capabilities, CallOptions, InMemoryRpcClient, Notifier, PubSubError,
+    Publisher, RegistrationError, RpcClientUSubscription, SimpleNotifier, Subscriber, UPayload,
 };
 
 #[derive(Clone)]
@@ -202,6 +203,52 @@ impl SimplePublisher {
             uri_provider,
         }
     }
+
+    fn build_publish_message(
+        &self,
+        resource_id: u16,
+        call_options: CallOptions,
+        payload: Option<UPayload>,
+    ) -> Result<UMessage, PubSubError> {
+        let mut builder = UMessageBuilder::publish(self.uri_provider.get_resource_uri(resource_id));
+        apply_common_options(call_options, &mut builder);
+        build_message(&mut builder, payload).map_err(|e| {
+            PubSubError::InvalidArgument(format!(
+                "failed to create Publish message from parameters: {}",
+                e
+            ))
+        })
+    }
+
+    /// Publishes multiple events at once.
+    ///
+    /// All messages are created before any of them is sent. The messages are then sent by means of
+    /// [`UTransport::send_batch`], which allows transports to deliver them more efficiently than
+    /// sending them one by one.
+    ///
+    /// # Arguments
+    ///
+    /// * `events` - The resource ID of the topic, the options and the payload of each event to publish.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the messages could not be created, in which case none of the
+    /// events is published, or if the transport failed to send the messages.
+    pub async fn publish_batch(
+        &self,
+        events: Vec<(u16, CallOptions, Option<UPayload>)>,
+    ) -> Result<(), PubSubError> {
+        let messages = events
+            .into_iter()
+            .map(|(resource_id, call_options, payload)| {
+                self.build_publish_message(resource_id, call_options, payload)
+            })
+            .collect::<Result<Vec<UMessage>, PubSubError>>()?;
+        self.transport
+            .send_batch(messages)
+            .await
+            .map_err(PubSubError::PublishError)
+    }
 }
 
 #[async_trait]
@@ -212,19 +259,11 @@ impl Publisher for SimplePublisher {
         call_options: CallOptions,
         payload: Option<UPayload>,
     ) -> Result<(), PubSubError> {
-        let mut builder = UMessageBuilder::publish(self.uri_provider.get_resource_uri(resource_id));
-        apply_common_options(call_options, &mut builder);
-        match build_message(&mut builder, payload) {
-            Ok(publish_message) => self
-                .transport
-                .send(publish_message)
-                .await
-                .map_err(PubSubError::PublishError),
-            Err(e) => Err(PubSubError::InvalidArgument(format!(
-                "failed to create Publish message from parameters: {}",
-                e
-            ))),
-        }
+        let publish_message = self.build_publish_message(resource_id, call_options, payload)?;
+        self.transport
+            .send(publish_message)
+            .await
+            .map_err(PubSubError::PublishError)
     }
 }
 
@@ -284,13 +323,15 @@ impl InMemorySubscriber {
     ///
     /// # Errors
     ///
-    /// Returns an error if the Notifier cannot register a listener for notifications from the USubscription service.
+    /// Returns an error if the given transport does not support the push delivery method or Publish messages,
+    /// or if the Notifier cannot register a listener for notifications from the USubscription service.
     pub async fn for_clients(
         transport: Arc<dyn UTransport>,
         uri_provider: Arc<dyn LocalUriProvider>,
         usubscription: Arc<dyn USubscription>,
         notifier: Arc<dyn Notifier>,
     ) -> Result<Self, RegistrationError> {
+        verify_transport_capabilities(transport.as_ref(), &[UMessageType::UMESSAGE_TYPE_PUBLISH])?;
         // register a generic listener for subscription updates
         // whenever a uE later tries to subscribe to a topic, it can provide an optional callback for
         // handling subscription updates for the topic it tries to subscribe to
@@ -521,6 +562,56 @@ mod tests {
         assert!(publish_result.is_err_and(|e| matches!(e, PubSubError::PublishError(_status))));
     }
 
+    #[tokio::test]
+    async fn test_publish_batch_fails_for_invalid_topic() {
+        // GIVEN a publisher
+        let uri_provider = new_uri_provider();
+        let mut transport = MockTransport::new();
+        transport.expect_do_send().never();
+        let publisher = SimplePublisher::new(Arc::new(transport), uri_provider);
+
+        // WHEN publishing a batch of events of which one refers to an invalid topic
+        let events = vec![
+            (0x9A00, CallOptions::for_publish(None, None, None), None),
+            (0x1000, CallOptions::for_publish(None, None, None), None),
+        ];
+        let publish_result = publisher.publish_batch(events).await;
+
+        // THEN publishing fails with an InvalidArgument error and no event is sent
+        assert!(publish_result.is_err_and(|e| matches!(e, PubSubError::InvalidArgument(_msg))));
+    }
+
+    #[tokio::test]
+    async fn test_publish_batch_sends_all_events_in_order() {
+        // GIVEN a publisher
+        let uri_provider = new_uri_provider();
+        let mut transport = MockTransport::new();
+        let mut sequence = Sequence::new();
+        for resource_id in [0x9A00_u32, 0x9A01] {
+            transport
+                .expect_do_send()
+                .once()
+                .in_sequence(&mut sequence)
+                .withf(move |message| {
+                    message.is_publish()
+                        && message
+                            .source()
+                            .is_some_and(|source| source.resource_id == resource_id)
+                })
+                .returning(|_msg| Ok(()));
+        }
+        let publisher = SimplePublisher::new(Arc::new(transport), uri_provider);
+
+        // WHEN publishing a batch of events
+        let events = vec![
+            (0x9A00, CallOptions::for_publish(None, None, None), None),
+            (0x9A01, CallOptions::for_publish(None, None, None), None),
+        ];
+
+        // THEN all events are sent in the given order
+        assert!(publisher.publish_batch(events).await.is_ok());
+    }
+
     #[tokio::test]
     async fn test_publish_succeeds() {
         // GIVEN a publisher
@@ -571,6 +662,43 @@ mod tests {
         assert!(publish_result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_subscriber_creation_fails_for_transport_without_publish_support() {
+        struct RpcOnlyTransport;
+
+        #[async_trait]
+        impl UTransport for RpcOnlyTransport {
+            async fn send(&self, _message: UMessage) -> Result<(), UStatus> {
+                Ok(())
+            }
+            fn capabilities(&self) -> crate::TransportCapabilities {
+                crate::TransportCapabilities::default().with_message_types(&[
+                    UMessageType::UMESSAGE_TYPE_REQUEST,
+                    UMessageType::UMESSAGE_TYPE_RESPONSE,
+                ])
+            }
+        }
+
+        // GIVEN a Notifier that is never used
+        let mut notifier = MockNotifier::new();
+        notifier.expect_start_listening().never();
+
+        // WHEN trying to create a Subscriber for a transport that does not support Publish messages
+        let creation_attempt = InMemorySubscriber::for_clients(
+            Arc::new(RpcOnlyTransport),
+            new_uri_provider(),
+            Arc::new(MockUSubscription::new()),
+            Arc::new(notifier),
+        )
+        .await;
+
+        // THEN creation fails with an UNIMPLEMENTED error
+        assert!(creation_attempt.is_err_and(|e| matches!(
+            e,
+            RegistrationError::Unknown(status) if status.get_code() == UCode::UNIMPLEMENTED
+        )));
+    }
+
     #[tokio::test]
     async fn test_subscriber_creation_fails_when_notifier_fails_to_register_listener() {
         // GIVEN a Notifier
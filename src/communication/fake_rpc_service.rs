@@ -0,0 +1,462 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+
+use crate::{UAttributes, UCode, UListener, UMessage, UStatus, UTransport, UUri};
+
+use super::{
+    in_memory_rpc_server::RequestListener, RequestHandler, ServiceInvocationError, UPayload,
+};
+
+type ResponseFn = dyn Fn(&UAttributes, Option<UPayload>) -> Result<Option<UPayload>, ServiceInvocationError>
+    + Send
+    + Sync;
+
+#[derive(Clone)]
+enum FakeResponse {
+    Payload(Option<UPayload>),
+    Error(ServiceInvocationError),
+    Function(Arc<ResponseFn>),
+}
+
+#[derive(Clone)]
+struct FakeMethod {
+    response: FakeResponse,
+    delay: Duration,
+}
+
+impl Default for FakeMethod {
+    fn default() -> Self {
+        FakeMethod {
+            response: FakeResponse::Payload(None),
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+#[async_trait]
+impl RequestHandler for FakeMethod {
+    async fn handle_request(
+        &self,
+        _resource_id: u16,
+        message_attributes: &UAttributes,
+        request_payload: Option<UPayload>,
+    ) -> Result<Option<UPayload>, ServiceInvocationError> {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        match &self.response {
+            FakeResponse::Payload(payload) => Ok(payload.to_owned()),
+            FakeResponse::Error(error) => Err(error.to_owned()),
+            FakeResponse::Function(function) => function(message_attributes, request_payload),
+        }
+    }
+}
+
+struct FakeMethodListener {
+    request_listener: Arc<RequestListener>,
+    requests: Arc<Mutex<Vec<UMessage>>>,
+}
+
+#[async_trait]
+impl UListener for FakeMethodListener {
+    async fn on_receive(&self, msg: UMessage) {
+        if let Ok(mut requests) = self.requests.lock() {
+            requests.push(msg.clone());
+        }
+        // process the request on a separate task so that a delayed response does not
+        // block the transport's delivery of other messages
+        let request_listener = self.request_listener.clone();
+        tokio::spawn(async move { request_listener.on_receive(msg).await });
+    }
+}
+
+/// A builder for a [`FakeRpcService`].
+///
+/// The builder is used for scripting the service's behavior for each of its methods.
+/// Configuring the same method multiple times replaces the previously configured response.
+#[derive(Default)]
+pub struct FakeRpcServiceBuilder {
+    methods: HashMap<UUri, FakeMethod>,
+}
+
+impl FakeRpcServiceBuilder {
+    /// Lets a method answer all requests with a canned payload.
+    pub fn respond_with(mut self, method: UUri, payload: Option<UPayload>) -> Self {
+        self.methods.entry(method).or_default().response = FakeResponse::Payload(payload);
+        self
+    }
+
+    /// Lets a method answer all requests with an error.
+    pub fn fail_with(mut self, method: UUri, error: ServiceInvocationError) -> Self {
+        self.methods.entry(method).or_default().response = FakeResponse::Error(error);
+        self
+    }
+
+    /// Lets a method answer requests by means of a function.
+    ///
+    /// The function is invoked with the attributes and the payload of each request
+    /// and its outcome is sent back to the client.
+    pub fn respond_with_fn<F>(mut self, method: UUri, function: F) -> Self
+    where
+        F: Fn(&UAttributes, Option<UPayload>) -> Result<Option<UPayload>, ServiceInvocationError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.methods.entry(method).or_default().response =
+            FakeResponse::Function(Arc::new(function));
+        self
+    }
+
+    /// Delays the responses of a method by a given amount of time.
+    ///
+    /// A method that has no response configured answers with an empty payload.
+    /// If the delay exceeds a request's TTL, the client receives a
+    /// [`ServiceInvocationError::DeadlineExceeded`] error instead.
+    pub fn with_delay(mut self, method: UUri, delay: Duration) -> Self {
+        self.methods.entry(method).or_default().delay = delay;
+        self
+    }
+
+    /// Attaches the service to a transport.
+    ///
+    /// Registers a listener for the requests to each of the configured methods, regardless of
+    /// the requests' origin.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the configured methods is not a valid RPC method URI or if any of
+    /// the listeners could not be registered with the transport. In this case, all listeners that have
+    /// already been registered are being unregistered again.
+    pub async fn attach(self, transport: Arc<dyn UTransport>) -> Result<FakeRpcService, UStatus> {
+        if let Some(method) = self.methods.keys().find(|method| !method.is_rpc_method()) {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                format!("not an RPC method: {}", method.to_uri(false)),
+            ));
+        }
+
+        let mut service = FakeRpcService {
+            transport: transport.clone(),
+            listeners: Vec::with_capacity(self.methods.len()),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        for (method, fake_method) in self.methods {
+            let listener: Arc<dyn UListener> = Arc::new(FakeMethodListener {
+                request_listener: Arc::new(RequestListener::new(
+                    Arc::new(fake_method),
+                    transport.clone(),
+                )),
+                requests: service.requests.clone(),
+            });
+            if let Err(e) = transport
+                .register_listener(&origin_filter(), Some(&method), listener.clone())
+                .await
+            {
+                // we are only interested in the registration error
+                let _ = service.detach().await;
+                return Err(e);
+            }
+            service.listeners.push((method, listener));
+        }
+        Ok(service)
+    }
+}
+
+fn origin_filter() -> UUri {
+    UUri::any_with_resource_id(crate::uri::RESOURCE_ID_RESPONSE)
+}
+
+/// A fake service provider for testing code that invokes RPC methods.
+///
+/// The service answers requests according to the responses that have been scripted for each method
+/// by means of a [`FakeRpcServiceBuilder`]. All requests that the service receives are being recorded so
+/// that tests can inspect them afterwards. This allows, for example, faking the responses of the uSubscription
+/// or uDiscovery services without having to implement a [`RequestHandler`].
+///
+/// Requests are processed on separate tasks, i.e. the service requires a tokio runtime.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use protobuf::well_known_types::wrappers::StringValue;
+/// use up_rust::{
+///     communication::{FakeRpcService, UPayload},
+///     RecordingTransport, UMessageBuilder, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = std::sync::Arc::new(RecordingTransport::default());
+/// let method = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+/// let greeting = StringValue {
+///     value: "Hello".to_string(),
+///     ..Default::default()
+/// };
+/// let service = FakeRpcService::builder()
+///     .respond_with(method.clone(), Some(UPayload::try_from_protobuf(greeting).unwrap()))
+///     .attach(transport.clone())
+///     .await
+///     .unwrap();
+///
+/// let request = UMessageBuilder::request(
+///     method.clone(),
+///     UUri::try_from("//my-vehicle/B100/1/0").unwrap(),
+///     5000,
+/// )
+/// .build()
+/// .unwrap();
+/// transport.inject(request.clone()).await;
+///
+/// let response = transport
+///     .expect_response(request.id().unwrap(), Duration::from_secs(1))
+///     .await;
+/// let reply: StringValue = response.extract_protobuf().unwrap();
+/// assert_eq!(reply.value, "Hello");
+/// assert_eq!(service.requests_for(&method), vec![request]);
+/// # }
+/// ```
+pub struct FakeRpcService {
+    transport: Arc<dyn UTransport>,
+    listeners: Vec<(UUri, Arc<dyn UListener>)>,
+    requests: Arc<Mutex<Vec<UMessage>>>,
+}
+
+impl FakeRpcService {
+    /// Creates a builder for scripting a service's behavior.
+    pub fn builder() -> FakeRpcServiceBuilder {
+        FakeRpcServiceBuilder::default()
+    }
+
+    /// Gets all requests that the service has received, in the order in which they have been received.
+    pub fn requests(&self) -> Vec<UMessage> {
+        self.requests
+            .lock()
+            .map(|requests| requests.clone())
+            .unwrap_or_default()
+    }
+
+    /// Gets the requests that the service has received for a particular method.
+    pub fn requests_for(&self, method: &UUri) -> Vec<UMessage> {
+        self.requests()
+            .into_iter()
+            .filter(|msg| msg.sink().is_some_and(|sink| method.matches(sink)))
+            .collect()
+    }
+
+    /// Detaches the service from the transport.
+    ///
+    /// Unregisters the listeners for all of the service's methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns the last error that occurred while unregistering the listeners.
+    pub async fn detach(mut self) -> Result<(), UStatus> {
+        let mut result = Ok(());
+        for (method, listener) in self.listeners.drain(..) {
+            if let Err(e) = self
+                .transport
+                .unregister_listener(&origin_filter(), Some(&method), listener)
+                .await
+            {
+                result = Err(e);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use protobuf::well_known_types::wrappers::StringValue;
+
+    use crate::{utransport::RecordingTransport, UMessageBuilder, UUID};
+
+    const TIMEOUT: Duration = Duration::from_secs(1);
+
+    fn method(resource_id: u16) -> UUri {
+        UUri::try_from_parts("my-vehicle", 0xA100, 0x01, resource_id).unwrap()
+    }
+
+    fn request(method: UUri, ttl: u32) -> UMessage {
+        UMessageBuilder::request(
+            method,
+            UUri::try_from("//my-vehicle/B100/1/0").unwrap(),
+            ttl,
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_service_answers_with_scripted_responses() {
+        let transport = Arc::new(RecordingTransport::default());
+        let service = FakeRpcService::builder()
+            .respond_with(method(1), None)
+            .fail_with(
+                method(2),
+                ServiceInvocationError::NotFound("no such topic".to_string()),
+            )
+            .respond_with_fn(method(3), |_attributes, payload| {
+                // echo the request payload
+                Ok(payload)
+            })
+            .attach(transport.clone())
+            .await
+            .unwrap();
+        assert_eq!(transport.listener_count(), 3);
+
+        let first = request(method(1), 5000);
+        transport.inject(first.clone()).await;
+        let response = transport
+            .expect_response(first.id().unwrap(), TIMEOUT)
+            .await;
+        assert!(response.commstatus().is_none_or(|code| code == UCode::OK));
+        assert!(response.payload.is_none());
+
+        let second = request(method(2), 5000);
+        transport.inject(second.clone()).await;
+        let response = transport
+            .expect_response(second.id().unwrap(), TIMEOUT)
+            .await;
+        assert_eq!(response.commstatus(), Some(UCode::NOT_FOUND));
+
+        let data = StringValue {
+            value: "Hello".to_string(),
+            ..Default::default()
+        };
+        let third = UMessageBuilder::request(
+            method(3),
+            UUri::try_from("//my-vehicle/B100/1/0").unwrap(),
+            5000,
+        )
+        .build_with_protobuf_payload(&data)
+        .unwrap();
+        transport.inject(third.clone()).await;
+        let response = transport
+            .expect_response(third.id().unwrap(), TIMEOUT)
+            .await;
+        assert_eq!(response.extract_protobuf::<StringValue>().unwrap(), data);
+
+        assert_eq!(service.requests(), vec![first, second, third.clone()]);
+        assert_eq!(service.requests_for(&method(3)), vec![third]);
+
+        service.detach().await.unwrap();
+        assert_eq!(transport.listener_count(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_delayed_response_exceeding_ttl_results_in_timeout() {
+        let transport = Arc::new(RecordingTransport::default());
+        let _service = FakeRpcService::builder()
+            .with_delay(method(1), Duration::from_secs(10))
+            .attach(transport.clone())
+            .await
+            .unwrap();
+
+        let request = request(method(1), 100);
+        let request_id: UUID = request.id().unwrap().to_owned();
+        transport.inject(request).await;
+
+        let response = transport
+            .expect_response(&request_id, Duration::from_secs(20))
+            .await;
+        assert_eq!(response.commstatus(), Some(UCode::DEADLINE_EXCEEDED));
+    }
+
+    #[tokio::test]
+    async fn test_attach_rejects_invalid_method() {
+        let transport = Arc::new(RecordingTransport::default());
+        let result = FakeRpcService::builder()
+            .respond_with(method(1), None)
+            .respond_with(method(0x8001), None)
+            .attach(transport.clone())
+            .await;
+        assert!(result.is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+        assert_eq!(transport.listener_count(), 0);
+    }
+
+    #[cfg(all(feature = "usubscription", feature = "util"))]
+    #[tokio::test]
+    async fn test_service_fakes_usubscription() {
+        use crate::{
+            communication::{InMemoryRpcClient, RpcClientUSubscription},
+            core::usubscription::{
+                usubscription_uri, State, SubscriptionRequest, SubscriptionResponse,
+                SubscriptionStatus, USubscription, RESOURCE_ID_SUBSCRIBE,
+            },
+            local_transport::LocalTransport,
+            StaticUriProvider,
+        };
+
+        let transport = Arc::new(LocalTransport::default());
+        let service = FakeRpcService::builder()
+            .respond_with_fn(
+                usubscription_uri(RESOURCE_ID_SUBSCRIBE),
+                |_attributes, payload| {
+                    let request: SubscriptionRequest = payload
+                        .ok_or_else(|| {
+                            ServiceInvocationError::InvalidArgument("no request".into())
+                        })?
+                        .extract_protobuf()
+                        .map_err(|e| ServiceInvocationError::InvalidArgument(e.to_string()))?;
+                    let response = SubscriptionResponse {
+                        topic: request.topic,
+                        status: Some(SubscriptionStatus {
+                            state: State::SUBSCRIBED.into(),
+                            ..Default::default()
+                        })
+                        .into(),
+                        ..Default::default()
+                    };
+                    UPayload::try_from_protobuf(response)
+                        .map(Some)
+                        .map_err(|e| ServiceInvocationError::Internal(e.to_string()))
+                },
+            )
+            .attach(transport.clone())
+            .await
+            .unwrap();
+        let rpc_client = InMemoryRpcClient::new(
+            transport,
+            Arc::new(StaticUriProvider::new("", 0xB100, 0x01)),
+        )
+        .await
+        .unwrap();
+        let usubscription = RpcClientUSubscription::new(Arc::new(rpc_client));
+
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let response = usubscription
+            .subscribe(SubscriptionRequest {
+                topic: Some(topic.clone()).into(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.topic.get_or_default(), &topic);
+        assert!(response.is_state(State::SUBSCRIBED));
+        assert_eq!(service.requests().len(), 1);
+    }
+}
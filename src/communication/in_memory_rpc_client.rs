@@ -24,12 +24,13 @@ use tokio::time::timeout;
 use tracing::{debug, info};
 
 use crate::{
-    LocalUriProvider, UCode, UListener, UMessage, UMessageBuilder, UMessageType, UStatus,
-    UTransport, UUri, UUID,
+    ConnectionState, ConnectionStateListener, LocalUriProvider, UCode, UListener, UMessage,
+    UMessageBuilder, UMessageType, UStatus, UTransport, UUri, UUID,
 };
 
 use super::{
-    build_message, CallOptions, RegistrationError, RpcClient, ServiceInvocationError, UPayload,
+    build_message, verify_transport_capabilities, CallOptions, RegistrationError, RpcClient,
+    ServiceInvocationError, UPayload,
 };
 
 fn handle_response_message(response: UMessage) -> Result<Option<UPayload>, ServiceInvocationError> {
@@ -59,16 +60,20 @@ fn handle_response_message(response: UMessage) -> Result<Option<UPayload>, Servi
     }
 }
 
+/// The outcome of a pending request, which is either the response message or an error
+/// indicating that no response can be received.
+type ResponseOutcome = Result<UMessage, ServiceInvocationError>;
+
 struct ResponseListener {
     // request ID -> sender for response message
-    pending_requests: Mutex<HashMap<UUID, Sender<UMessage>>>,
+    pending_requests: Mutex<HashMap<UUID, Sender<ResponseOutcome>>>,
 }
 
 impl ResponseListener {
     fn try_add_pending_request(
         &self,
         reqid: UUID,
-    ) -> Result<Receiver<UMessage>, ServiceInvocationError> {
+    ) -> Result<Receiver<ResponseOutcome>, ServiceInvocationError> {
         let Ok(mut pending_requests) = self.pending_requests.lock() else {
             return Err(ServiceInvocationError::Internal(
                 "failed to add response handler".to_string(),
@@ -95,7 +100,7 @@ impl ResponseListener {
             return;
         };
         if let Some(sender) = pending_requests.remove(reqid) {
-            if let Err(_e) = sender.send(response_message) {
+            if let Err(_e) = sender.send(Ok(response_message)) {
                 // channel seems to be closed already
                 debug!(
                     request_id = reqid.to_hyphenated_string(),
@@ -116,7 +121,22 @@ impl ResponseListener {
         }
     }
 
-    fn remove_pending_request(&self, reqid: &UUID) -> Option<Sender<UMessage>> {
+    /// Fails all pending requests with an [`ServiceInvocationError::Unavailable`] error.
+    fn fail_pending_requests(&self, reason: &str) {
+        let Ok(mut pending_requests) = self.pending_requests.lock() else {
+            info!("failed to fail pending requests, cannot acquire lock for pending requests map");
+            return;
+        };
+        for (reqid, sender) in pending_requests.drain() {
+            debug!(
+                request_id = reqid.to_hyphenated_string(),
+                "failing pending RPC request: {reason}"
+            );
+            let _ = sender.send(Err(ServiceInvocationError::Unavailable(reason.to_string())));
+        }
+    }
+
+    fn remove_pending_request(&self, reqid: &UUID) -> Option<Sender<ResponseOutcome>> {
         self.pending_requests
             .lock()
             .map_or(None, |mut pending_requests| pending_requests.remove(reqid))
@@ -158,6 +178,15 @@ impl UListener for ResponseListener {
     }
 }
 
+impl ConnectionStateListener for ResponseListener {
+    fn on_state_change(&self, state: ConnectionState) {
+        if state != ConnectionState::Connected {
+            // responses to pending requests cannot be received anymore
+            self.fail_pending_requests("transport has lost its connection");
+        }
+    }
+}
+
 /// An [`RpcClient`] which keeps all information about pending requests in memory.
 ///
 /// The client requires an implementations of [`UTransport`] for sending RPC Request messages
@@ -171,6 +200,11 @@ impl UListener for ResponseListener {
 /// implementation and a response handler is created and registered with the listener.
 /// When an RPC Response message arrives from the service, the corresponding handler is being looked
 /// up and invoked.
+///
+/// The client also registers a [`ConnectionStateListener`] with the transport, if supported.
+/// Pending requests fail with a [`ServiceInvocationError::Unavailable`] as soon as the transport
+/// loses its connection, instead of waiting for their TTL to expire. The connection state listener
+/// is unregistered again when the client is dropped.
 pub struct InMemoryRpcClient {
     transport: Arc<dyn UTransport>,
     uri_provider: Arc<dyn LocalUriProvider>,
@@ -187,12 +221,19 @@ impl InMemoryRpcClient {
     ///
     /// # Errors
     ///
-    /// Returns an error if the generic RPC Response listener could not be
-    /// registered with the given transport.
+    /// Returns an error if the given transport does not support the push delivery method or
+    /// RPC messages, or if the generic RPC Response listener could not be registered with the transport.
     pub async fn new(
         transport: Arc<dyn UTransport>,
         uri_provider: Arc<dyn LocalUriProvider>,
     ) -> Result<Self, RegistrationError> {
+        verify_transport_capabilities(
+            transport.as_ref(),
+            &[
+                UMessageType::UMESSAGE_TYPE_REQUEST,
+                UMessageType::UMESSAGE_TYPE_RESPONSE,
+            ],
+        )?;
         let response_listener = Arc::new(ResponseListener {
             pending_requests: Mutex::new(HashMap::new()),
         });
@@ -204,6 +245,9 @@ impl InMemoryRpcClient {
             )
             .await
             .map_err(RegistrationError::from)?;
+        if let Err(e) = transport.register_connection_state_listener(response_listener.clone()) {
+            debug!("transport does not report changes of its connection state: {e}");
+        }
 
         Ok(InMemoryRpcClient {
             transport,
@@ -218,6 +262,19 @@ impl InMemoryRpcClient {
     }
 }
 
+impl Drop for InMemoryRpcClient {
+    fn drop(&mut self) {
+        // the RPC Response listener cannot be unregistered here because doing so requires an async
+        // context, but the connection state listener must not outlive the client
+        if let Err(e) = self
+            .transport
+            .unregister_connection_state_listener(self.response_listener.clone())
+        {
+            debug!("failed to unregister connection state listener: {e}");
+        }
+    }
+}
+
 #[async_trait]
 impl RpcClient for InMemoryRpcClient {
     async fn invoke_method(
@@ -243,9 +300,17 @@ impl RpcClient for InMemoryRpcClient {
         let rpc_request_message = build_message(&mut builder, payload)
             .map_err(|e| ServiceInvocationError::InvalidArgument(e.to_string()))?;
 
+        // the pending request needs to be registered before checking the connection state,
+        // otherwise a loss of connection in between would not fail the request
         let receiver = self
             .response_listener
             .try_add_pending_request(message_id.clone())?;
+        if self.transport.connection_state() != ConnectionState::Connected {
+            self.response_listener.remove_pending_request(&message_id);
+            return Err(ServiceInvocationError::Unavailable(
+                "transport is not connected".to_string(),
+            ));
+        }
         self.transport
             .send(rpc_request_message)
             .await
@@ -269,7 +334,8 @@ impl RpcClient for InMemoryRpcClient {
                 Err(ServiceInvocationError::DeadlineExceeded)
             }
             Ok(result) => match result {
-                Ok(response_message) => handle_response_message(response_message),
+                Ok(Ok(response_message)) => handle_response_message(response_message),
+                Ok(Err(e)) => Err(e),
                 Err(_e) => {
                     debug!(
                         request_id = message_id.to_hyphenated_string(),
@@ -310,6 +376,31 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_creation_fails_for_transport_without_push_delivery() {
+        struct PullOnlyTransport;
+
+        #[async_trait]
+        impl UTransport for PullOnlyTransport {
+            async fn send(&self, _message: UMessage) -> Result<(), UStatus> {
+                Ok(())
+            }
+            fn capabilities(&self) -> crate::TransportCapabilities {
+                crate::TransportCapabilities::default()
+                    .with_push_delivery(false)
+                    .with_pull_delivery(true)
+            }
+        }
+
+        // WHEN trying to create an RpcClient for a transport that does not support listeners
+        let creation_attempt =
+            InMemoryRpcClient::new(Arc::new(PullOnlyTransport), new_uri_provider()).await;
+
+        // THEN the attempt fails with a PushDeliveryMethodNotSupported error
+        assert!(creation_attempt
+            .is_err_and(|e| matches!(e, RegistrationError::PushDeliveryMethodNotSupported)));
+    }
+
     #[tokio::test]
     async fn test_registration_of_response_listener_fails() {
         // GIVEN a transport
@@ -369,6 +460,111 @@ mod tests {
         assert!(!client.contains_pending_request(&message_id));
     }
 
+    /// A transport that accepts all messages and listeners and whose connection state can be changed.
+    struct ConnectionAwareTransport {
+        connection: crate::ConnectionStateNotifier,
+    }
+
+    #[async_trait]
+    impl UTransport for ConnectionAwareTransport {
+        async fn send(&self, _message: UMessage) -> Result<(), UStatus> {
+            Ok(())
+        }
+        fn connection_state(&self) -> ConnectionState {
+            self.connection.state()
+        }
+        fn register_connection_state_listener(
+            &self,
+            listener: Arc<dyn ConnectionStateListener>,
+        ) -> Result<(), UStatus> {
+            self.connection.add_listener(listener)
+        }
+        fn unregister_connection_state_listener(
+            &self,
+            listener: Arc<dyn ConnectionStateListener>,
+        ) -> Result<(), UStatus> {
+            self.connection.remove_listener(listener)
+        }
+        async fn register_listener(
+            &self,
+            _source_filter: &UUri,
+            _sink_filter: Option<&UUri>,
+            _listener: Arc<dyn UListener>,
+        ) -> Result<(), UStatus> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_pending_request_fails_when_connection_is_lost() {
+        // GIVEN an RPC client for a connected transport
+        let transport = Arc::new(ConnectionAwareTransport {
+            connection: crate::ConnectionStateNotifier::new(ConnectionState::Connected),
+        });
+        let client = Arc::new(
+            InMemoryRpcClient::new(transport.clone(), new_uri_provider())
+                .await
+                .unwrap(),
+        );
+
+        // with a pending request
+        let message_id = UUID::build();
+        let call_options =
+            CallOptions::for_rpc_request(60_000, Some(message_id.clone()), None, None);
+        let invoking_client = client.clone();
+        let invocation = tokio::spawn(async move {
+            invoking_client
+                .invoke_method(service_method_uri(), call_options, None)
+                .await
+        });
+        while !client.contains_pending_request(&message_id) {
+            tokio::task::yield_now().await;
+        }
+
+        // WHEN the transport loses its connection
+        transport
+            .connection
+            .set_state(ConnectionState::Reconnecting);
+
+        // THEN the pending request fails right away
+        let result = tokio::time::timeout(Duration::from_secs(1), invocation)
+            .await
+            .expect("pending request has not failed")
+            .unwrap();
+        assert!(result.is_err_and(|e| matches!(e, ServiceInvocationError::Unavailable(_msg))));
+        assert!(!client.contains_pending_request(&message_id));
+
+        // and new requests are rejected until the connection has been re-established
+        let rejected_message_id = UUID::build();
+        let result = client
+            .invoke_method(
+                service_method_uri(),
+                CallOptions::for_rpc_request(60_000, Some(rejected_message_id.clone()), None, None),
+                None,
+            )
+            .await;
+        assert!(result.is_err_and(|e| matches!(e, ServiceInvocationError::Unavailable(_msg))));
+        assert!(!client.contains_pending_request(&rejected_message_id));
+    }
+
+    #[tokio::test]
+    async fn test_connection_state_listener_is_unregistered_on_drop() {
+        // GIVEN an RPC client for a transport that reports its connection state
+        let transport = Arc::new(ConnectionAwareTransport {
+            connection: crate::ConnectionStateNotifier::new(ConnectionState::Connected),
+        });
+        let client = InMemoryRpcClient::new(transport.clone(), new_uri_provider())
+            .await
+            .unwrap();
+        let response_listener = Arc::downgrade(&client.response_listener);
+
+        // WHEN the client is dropped
+        drop(client);
+
+        // THEN the transport no longer holds on to the client's listener
+        assert!(response_listener.upgrade().is_none());
+    }
+
     #[tokio::test]
     async fn test_invoke_method_succeeds() {
         let message_id = UUID::build();
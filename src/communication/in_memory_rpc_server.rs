@@ -28,12 +28,24 @@ use crate::{
 
 use super::{RegistrationError, RequestHandler, RpcServer, ServiceInvocationError, UPayload};
 
-struct RequestListener {
+/// A [`UListener`] that processes RPC Request messages by means of a [`RequestHandler`]
+/// and sends back the corresponding RPC Response messages.
+pub(super) struct RequestListener {
     request_handler: Arc<dyn RequestHandler>,
     transport: Arc<dyn UTransport>,
 }
 
 impl RequestListener {
+    pub(super) fn new(
+        request_handler: Arc<dyn RequestHandler>,
+        transport: Arc<dyn UTransport>,
+    ) -> Self {
+        RequestListener {
+            request_handler,
+            transport,
+        }
+    }
+
     async fn process_valid_request(&self, resource_id: u16, request_message: UMessage) {
         let transport_clone = self.transport.clone();
         let request_handler_clone = self.request_handler.clone();
@@ -228,10 +240,10 @@ impl RpcServer for InMemoryRpcServer {
 
         let mut listener_map = self.request_listeners.lock().await;
         if let Entry::Vacant(e) = listener_map.entry(resource_id) {
-            let listener = Arc::new(RequestListener {
+            let listener = Arc::new(RequestListener::new(
                 request_handler,
-                transport: self.transport.clone(),
-            });
+                self.transport.clone(),
+            ));
             self.transport
                 .register_listener(
                     origin_filter.unwrap_or(&UUri::any_with_resource_id(
@@ -294,8 +306,9 @@ mod tests {
     use tokio::sync::Notify;
 
     use crate::{
-        communication::rpc::MockRequestHandler, utransport::MockTransport, StaticUriProvider,
-        UAttributes, UMessageType, UPriority, UUri, UUID,
+        communication::{rpc::MockRequestHandler, FakeRpcService},
+        utransport::{MockTransport, RecordingTransport},
+        StaticUriProvider, UAttributes, UMessageType, UPriority, UUri, UUID,
     };
 
     fn new_uri_provider() -> Arc<dyn LocalUriProvider> {
@@ -656,64 +669,31 @@ mod tests {
 
     #[tokio::test]
     async fn test_request_listener_times_out() {
-        // we need to manually implement the RequestHandler
-        // because from within the MockRequestHandler's expectation
-        // we cannot yield the current task (we can only use the blocking
-        // thread::sleep function)
-        struct NonRespondingHandler;
-        #[async_trait]
-        impl RequestHandler for NonRespondingHandler {
-            async fn handle_request(
-                &self,
-                resource_id: u16,
-                _message_attributes: &UAttributes,
-                _request_payload: Option<UPayload>,
-            ) -> Result<Option<UPayload>, ServiceInvocationError> {
-                assert_eq!(resource_id, 0x7000);
-                // this will yield the current task and allow the
-                // RequestListener to run into the timeout
-                tokio::time::sleep(Duration::from_millis(2000)).await;
-                Ok(None)
-            }
-        }
-
-        let request_handler = NonRespondingHandler {};
-        let mut transport = MockTransport::new();
-        let notify = Arc::new(Notify::new());
-        let notify_clone = notify.clone();
+        let transport = Arc::new(RecordingTransport::default());
+        let method = UUri::try_from("up://localhost/A200/1/7000").unwrap();
+        let _service = FakeRpcService::builder()
+            // this will allow the RequestListener to run into the timeout
+            .with_delay(method.clone(), Duration::from_millis(2000))
+            .attach(transport.clone())
+            .await
+            .unwrap();
         let message_id = UUID::build();
-        let message_id_clone = message_id.clone();
-
-        transport
-            .expect_do_send()
-            .once()
-            .withf(move |response_message| {
-                let error: UStatus = response_message.extract_protobuf().unwrap();
-                error.get_code() == UCode::DEADLINE_EXCEEDED
-                    && response_message.is_response()
-                    && response_message.commstatus_unchecked() == error.get_code()
-                    && response_message.request_id_unchecked() == &message_id_clone
-            })
-            .returning(move |_msg| {
-                notify_clone.notify_one();
-                Ok(())
-            });
         let request_message = UMessageBuilder::request(
-            UUri::try_from("up://localhost/A200/1/7000").unwrap(),
+            method,
             UUri::try_from("up://localhost/A100/1/0").unwrap(),
             // make sure this request times out very quickly
             100,
         )
-        .with_message_id(message_id)
+        .with_message_id(message_id.clone())
         .build()
         .expect("should have been able to create RPC Request message");
 
-        let request_listener = RequestListener {
-            request_handler: Arc::new(request_handler),
-            transport: Arc::new(transport),
-        };
-        request_listener.on_receive(request_message).await;
-        let result = tokio::time::timeout(Duration::from_secs(2), notify.notified()).await;
-        assert!(result.is_ok());
+        transport.inject(request_message).await;
+        let response_message = transport
+            .expect_response(&message_id, Duration::from_secs(2))
+            .await;
+        let error: UStatus = response_message.extract_protobuf().unwrap();
+        assert_eq!(error.get_code(), UCode::DEADLINE_EXCEEDED);
+        assert_eq!(response_message.commstatus_unchecked(), error.get_code());
     }
 }
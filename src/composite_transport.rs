@@ -0,0 +1,563 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which delegates to other UTransports based on the authority
+that messages are exchanged with.
+*/
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, Weak},
+};
+
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::{
+    ConnectionState, ConnectionStateListener, ConnectionStateNotifier, TransportCapabilities,
+    UCode, UListener, UMessage, UStatus, UTransport, UUri,
+};
+
+/// Keeps track of the overall connection state of a [`CompositeTransport`]'s child transports.
+struct ChildConnectionStates {
+    // weak references prevent a reference cycle, because the child transports hold on to this listener
+    transports: Vec<Weak<dyn UTransport>>,
+    notifier: ConnectionStateNotifier,
+}
+
+impl ChildConnectionStates {
+    fn current_state(&self) -> ConnectionState {
+        ConnectionState::worst_of(
+            self.transports
+                .iter()
+                .filter_map(Weak::upgrade)
+                .map(|transport| transport.connection_state()),
+        )
+    }
+}
+
+impl ConnectionStateListener for ChildConnectionStates {
+    fn on_state_change(&self, _state: ConnectionState) {
+        self.notifier.set_state(self.current_state());
+    }
+}
+
+/// A [`UTransport`] that uses different child transports for exchanging messages with
+/// different authorities.
+///
+/// A message is sent via the child transport that is responsible for the authority of the
+/// message's sink address. Publish messages, which do not contain a sink address, are sent via the
+/// child transport that is responsible for the authority of the message's source address.
+/// The child transport for an authority is determined as follows:
+///
+/// 1. The _local_ transport is used for the local authority and for empty authority names.
+/// 2. A _remote_ transport that has been registered for the particular authority is used.
+/// 3. The _default_ transport is used for all other authorities.
+///
+/// Listeners are registered with the child transports that messages matching the listener's
+/// source filter can arrive on, i.e. with all child transports if the source filter contains
+/// the wildcard authority.
+///
+/// This allows uEntities to communicate with local and remote uEntities by means of
+/// a single transport instance, e.g. when using the Communication Layer API implementations.
+///
+/// The transport's [capabilities](UTransport::capabilities) are those common to all child transports.
+/// Its [connection state](UTransport::connection_state) is the worst state of any of the child
+/// transports, i.e. the transport is only considered connected if all child transports are connected.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use up_rust::{composite_transport::CompositeTransport, local_transport::LocalTransport};
+///
+/// let transport = CompositeTransport::new("my-vehicle", Arc::new(LocalTransport::default()))
+///     .with_remote_transport("my-cloud", Arc::new(LocalTransport::default()))
+///     .with_default_transport(Arc::new(LocalTransport::default()));
+/// ```
+pub struct CompositeTransport {
+    local_authority: String,
+    local_transport: Arc<dyn UTransport>,
+    remote_transports: HashMap<String, Arc<dyn UTransport>>,
+    default_transport: Option<Arc<dyn UTransport>>,
+    child_connection_states: Mutex<Option<Arc<ChildConnectionStates>>>,
+}
+
+impl CompositeTransport {
+    /// Creates a new transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `local_authority` - The name of the local authority.
+    /// * `local_transport` - The transport to use for exchanging messages with uEntities of the local authority.
+    pub fn new(local_authority: &str, local_transport: Arc<dyn UTransport>) -> Self {
+        CompositeTransport {
+            local_authority: local_authority.to_string(),
+            local_transport,
+            remote_transports: HashMap::new(),
+            default_transport: None,
+            child_connection_states: Mutex::new(None),
+        }
+    }
+
+    /// Sets the transport to use for exchanging messages with uEntities of a particular remote authority.
+    pub fn with_remote_transport(
+        mut self,
+        authority: &str,
+        transport: Arc<dyn UTransport>,
+    ) -> Self {
+        self.remote_transports
+            .insert(authority.to_string(), transport);
+        self
+    }
+
+    /// Sets the transport to use for exchanging messages with uEntities of all remote authorities
+    /// that no specific transport has been set for.
+    pub fn with_default_transport(mut self, transport: Arc<dyn UTransport>) -> Self {
+        self.default_transport = Some(transport);
+        self
+    }
+
+    /// Gets the child transport that is responsible for an authority.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::NOT_FOUND`] if no transport is responsible for the authority.
+    fn transport_for_authority(&self, authority: &str) -> Result<&Arc<dyn UTransport>, UStatus> {
+        if authority.is_empty() || authority == self.local_authority {
+            return Ok(&self.local_transport);
+        }
+        self.remote_transports
+            .get(authority)
+            .or(self.default_transport.as_ref())
+            .ok_or_else(|| {
+                UStatus::fail_with_code(
+                    UCode::NOT_FOUND,
+                    format!("no transport available for authority {authority}"),
+                )
+            })
+    }
+
+    /// Gets all distinct child transports.
+    fn all_transports(&self) -> Vec<&Arc<dyn UTransport>> {
+        let mut transports: Vec<&Arc<dyn UTransport>> = vec![];
+        for transport in std::iter::once(&self.local_transport)
+            .chain(self.remote_transports.values())
+            .chain(self.default_transport.iter())
+        {
+            if !transports.iter().any(|t| Arc::ptr_eq(t, transport)) {
+                transports.push(transport);
+            }
+        }
+        transports
+    }
+
+    /// Gets the tracker for the child transports' connection states, registering it with
+    /// the child transports on first use.
+    fn child_connection_states(&self) -> Result<Arc<ChildConnectionStates>, UStatus> {
+        let mut child_connection_states = self.child_connection_states.lock().map_err(|_e| {
+            UStatus::fail_with_code(
+                UCode::INTERNAL,
+                "failed to acquire lock for connection state",
+            )
+        })?;
+        if let Some(states) = child_connection_states.as_ref() {
+            return Ok(states.clone());
+        }
+        let transports = self.all_transports();
+        let states = Arc::new(ChildConnectionStates {
+            transports: transports.iter().map(|t| Arc::downgrade(*t)).collect(),
+            notifier: ConnectionStateNotifier::new(self.connection_state()),
+        });
+        for transport in transports {
+            if let Err(e) = transport.register_connection_state_listener(states.clone()) {
+                debug!("child transport does not report changes of its connection state: {e}");
+            }
+        }
+        *child_connection_states = Some(states.clone());
+        Ok(states)
+    }
+
+    /// Gets the child transports that messages matching a source filter can arrive on.
+    fn transports_for_source_filter(
+        &self,
+        source_filter: &UUri,
+    ) -> Result<Vec<&Arc<dyn UTransport>>, UStatus> {
+        if source_filter.has_wildcard_authority() {
+            Ok(self.all_transports())
+        } else {
+            self.transport_for_authority(&source_filter.authority_name)
+                .map(|transport| vec![transport])
+        }
+    }
+}
+
+impl Drop for CompositeTransport {
+    fn drop(&mut self) {
+        let Some(states) = self
+            .child_connection_states
+            .get_mut()
+            .ok()
+            .and_then(|states| states.take())
+        else {
+            return;
+        };
+        for transport in self.all_transports() {
+            let _ = transport.unregister_connection_state_listener(states.clone());
+        }
+    }
+}
+
+#[async_trait]
+impl UTransport for CompositeTransport {
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        let Some(destination) = message.sink().or(message.source()) else {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "message has neither source nor sink address",
+            ));
+        };
+        self.transport_for_authority(&destination.authority_name)?
+            .send(message)
+            .await
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        let capabilities: Vec<TransportCapabilities> = self
+            .all_transports()
+            .iter()
+            .map(|transport| transport.capabilities())
+            .collect();
+        // messages cannot be received by means of the pull delivery method
+        TransportCapabilities::common_to(&capabilities).with_pull_delivery(false)
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        ConnectionState::worst_of(
+            self.all_transports()
+                .iter()
+                .map(|transport| transport.connection_state()),
+        )
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.child_connection_states()?
+            .notifier
+            .add_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.child_connection_states()?
+            .notifier
+            .remove_listener(listener)
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let transports = self.transports_for_source_filter(source_filter)?;
+        for (idx, transport) in transports.iter().enumerate() {
+            if let Err(e) = transport
+                .register_listener(source_filter, sink_filter, listener.clone())
+                .await
+            {
+                // roll back the registrations that have succeeded so far
+                for registered in &transports[..idx] {
+                    let _ = registered
+                        .unregister_listener(source_filter, sink_filter, listener.clone())
+                        .await;
+                }
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let mut result = Ok(());
+        for transport in self.transports_for_source_filter(source_filter)? {
+            if let Err(e) = transport
+                .unregister_listener(source_filter, sink_filter, listener.clone())
+                .await
+            {
+                result = Err(e);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        local_transport::LocalTransport,
+        utransport::{MockTransport, MockUListener},
+        MessageOrdering, UMessageBuilder, UMessageType,
+    };
+
+    /// A listener that records all connection state changes.
+    #[derive(Default)]
+    struct StateRecorder(Mutex<Vec<ConnectionState>>);
+
+    impl ConnectionStateListener for StateRecorder {
+        fn on_state_change(&self, state: ConnectionState) {
+            self.0.lock().unwrap().push(state);
+        }
+    }
+
+    /// A remote transport with a fixed set of capabilities and a connection state that can be changed.
+    struct RemoteTransport {
+        capabilities: TransportCapabilities,
+        connection: ConnectionStateNotifier,
+    }
+
+    impl RemoteTransport {
+        fn new(capabilities: TransportCapabilities) -> Arc<Self> {
+            Arc::new(RemoteTransport {
+                capabilities,
+                connection: ConnectionStateNotifier::new(ConnectionState::Connected),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl UTransport for RemoteTransport {
+        async fn send(&self, _message: UMessage) -> Result<(), UStatus> {
+            Ok(())
+        }
+        fn capabilities(&self) -> TransportCapabilities {
+            self.capabilities.clone()
+        }
+        fn connection_state(&self) -> ConnectionState {
+            self.connection.state()
+        }
+        fn register_connection_state_listener(
+            &self,
+            listener: Arc<dyn ConnectionStateListener>,
+        ) -> Result<(), UStatus> {
+            self.connection.add_listener(listener)
+        }
+        fn unregister_connection_state_listener(
+            &self,
+            listener: Arc<dyn ConnectionStateListener>,
+        ) -> Result<(), UStatus> {
+            self.connection.remove_listener(listener)
+        }
+        async fn register_listener(
+            &self,
+            _source_filter: &UUri,
+            _sink_filter: Option<&UUri>,
+            _listener: Arc<dyn UListener>,
+        ) -> Result<(), UStatus> {
+            Ok(())
+        }
+    }
+
+    fn transport_expecting_sends(expected_sends: usize) -> Arc<MockTransport> {
+        let mut transport = MockTransport::new();
+        transport
+            .expect_do_send()
+            .times(expected_sends)
+            .returning(|_msg| Ok(()));
+        Arc::new(transport)
+    }
+
+    #[tokio::test]
+    async fn test_send_uses_transport_for_destination_authority() {
+        let local_transport = transport_expecting_sends(2);
+        let cloud_transport = transport_expecting_sends(1);
+        let default_transport = transport_expecting_sends(1);
+        let transport = CompositeTransport::new("my-vehicle", local_transport)
+            .with_remote_transport("my-cloud", cloud_transport)
+            .with_default_transport(default_transport);
+
+        let publish = UMessageBuilder::publish(
+            UUri::try_from_parts("my-vehicle", 0x1000, 0x01, 0x8001).unwrap(),
+        )
+        .build()
+        .unwrap();
+        assert!(transport.send(publish).await.is_ok());
+
+        let reply_to = UUri::try_from_parts("my-vehicle", 0x2000, 0x01, 0x0000).unwrap();
+        for authority in ["my-vehicle", "my-cloud", "other-vehicle"] {
+            let method = UUri::try_from_parts(authority, 0x1000, 0x01, 0x0001).unwrap();
+            let request = UMessageBuilder::request(method, reply_to.clone(), 5000)
+                .build()
+                .unwrap();
+            assert!(transport.send(request).await.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_for_unknown_authority_without_default_transport() {
+        let transport = CompositeTransport::new("my-vehicle", transport_expecting_sends(0))
+            .with_remote_transport("my-cloud", transport_expecting_sends(0));
+
+        let method = UUri::try_from_parts("other-vehicle", 0x1000, 0x01, 0x0001).unwrap();
+        let reply_to = UUri::try_from_parts("my-vehicle", 0x2000, 0x01, 0x0000).unwrap();
+        let request = UMessageBuilder::request(method, reply_to, 5000)
+            .build()
+            .unwrap();
+        assert!(transport
+            .send(request)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_register_listener_fans_out_to_relevant_transports() {
+        let mut local_transport = MockTransport::new();
+        local_transport
+            .expect_do_register_listener()
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        let mut cloud_transport = MockTransport::new();
+        cloud_transport
+            .expect_do_register_listener()
+            .times(2)
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        let transport = CompositeTransport::new("my-vehicle", Arc::new(local_transport))
+            .with_remote_transport("my-cloud", Arc::new(cloud_transport));
+        let listener = Arc::new(MockUListener::new());
+
+        // a listener for any source is registered with all transports
+        let reply_to = UUri::try_from_parts("my-vehicle", 0x2000, 0x01, 0x0000).unwrap();
+        assert!(transport
+            .register_listener(&UUri::any(), Some(&reply_to), listener.clone())
+            .await
+            .is_ok());
+        // a listener for a particular remote source is registered with the remote transport only
+        let topic = UUri::try_from("//my-cloud/1000/1/8001").unwrap();
+        assert!(transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .is_ok());
+        // a listener for an unknown remote source cannot be registered
+        let topic = UUri::try_from("//other-vehicle/1000/1/8001").unwrap();
+        assert!(transport
+            .register_listener(&topic, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_register_listener_rolls_back_on_failure() {
+        let mut local_transport = MockTransport::new();
+        local_transport
+            .expect_do_register_listener()
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        local_transport
+            .expect_do_unregister_listener()
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        let mut cloud_transport = MockTransport::new();
+        cloud_transport
+            .expect_do_register_listener()
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| {
+                Err(UStatus::fail_with_code(
+                    UCode::RESOURCE_EXHAUSTED,
+                    "too many listeners",
+                ))
+            });
+        let transport = CompositeTransport::new("my-vehicle", Arc::new(local_transport))
+            .with_remote_transport("my-cloud", Arc::new(cloud_transport));
+
+        assert!(transport
+            .register_listener(&UUri::any(), None, Arc::new(MockUListener::new()))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+    }
+
+    #[test]
+    fn test_capabilities_are_common_to_all_child_transports() {
+        let cloud_transport = RemoteTransport::new(
+            TransportCapabilities::default()
+                .with_push_delivery(true)
+                .with_message_types(&[UMessageType::UMESSAGE_TYPE_PUBLISH])
+                .with_max_message_size(1024),
+        );
+        let transport = CompositeTransport::new("my-vehicle", Arc::new(LocalTransport::default()))
+            .with_remote_transport("my-cloud", cloud_transport);
+
+        let capabilities = transport.capabilities();
+        assert_eq!(capabilities.supports_push_delivery(), Some(true));
+        assert_eq!(capabilities.supports_pull_delivery(), Some(false));
+        assert_eq!(
+            capabilities.supports_message_type(UMessageType::UMESSAGE_TYPE_PUBLISH),
+            Some(true)
+        );
+        assert_eq!(
+            capabilities.supports_message_type(UMessageType::UMESSAGE_TYPE_REQUEST),
+            Some(false)
+        );
+        assert_eq!(capabilities.max_message_size(), Some(1024));
+        assert_eq!(capabilities.ordering(), MessageOrdering::Unordered);
+    }
+
+    #[test]
+    fn test_connection_state_reflects_child_transports() {
+        // GIVEN a composite transport with a remote transport that is connected
+        let cloud_transport = RemoteTransport::new(TransportCapabilities::default());
+        let transport = CompositeTransport::new("my-vehicle", Arc::new(LocalTransport::default()))
+            .with_remote_transport("my-cloud", cloud_transport.clone());
+        assert_eq!(transport.connection_state(), ConnectionState::Connected);
+
+        // and a listener for changes of the composite transport's connection state
+        let listener = Arc::new(StateRecorder::default());
+        transport
+            .register_connection_state_listener(listener.clone())
+            .unwrap();
+
+        // WHEN the remote transport loses and re-establishes its connection
+        cloud_transport
+            .connection
+            .set_state(ConnectionState::Reconnecting);
+        assert_eq!(transport.connection_state(), ConnectionState::Reconnecting);
+        cloud_transport
+            .connection
+            .set_state(ConnectionState::Connected);
+        assert_eq!(transport.connection_state(), ConnectionState::Connected);
+
+        // THEN the listener has been informed about both changes
+        assert_eq!(
+            *listener.0.lock().unwrap(),
+            vec![ConnectionState::Reconnecting, ConnectionState::Connected]
+        );
+
+        // and is no longer informed once the composite transport has been dropped
+        drop(transport);
+        cloud_transport
+            .connection
+            .set_state(ConnectionState::Disconnected);
+        assert_eq!(listener.0.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,503 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a suite of tests for verifying that a [`UTransport`] implementation complies with the
+rules defined by the uProtocol Transport Layer specification.
+
+The suite covers the rules that every transport needs to implement in the same way, e.g. which
+filter criteria are accepted when registering a listener, which errors are returned for duplicate or
+unknown registrations and which messages are delivered to a listener depending on its source and
+sink filters.
+*/
+#![cfg_attr(feature = "util", doc = "```rust")]
+#![cfg_attr(not(feature = "util"), doc = "```rust,ignore")]
+/*!
+use std::sync::Arc;
+use up_rust::{conformance::ConformanceSuite, local_transport::LocalTransport, UTransport};
+
+# #[tokio::main(flavor = "current_thread")]
+# async fn main() {
+let suite = ConformanceSuite::new(|| async {
+    Arc::new(LocalTransport::default().with_strict_mode(true)) as Arc<dyn UTransport>
+});
+assert!(suite.run().await.is_ok());
+# }
+```
+*/
+
+use std::{future::Future, sync::Arc, time::Duration};
+
+use tokio::sync::mpsc;
+
+use crate::{
+    utransport::forwarding_listener, UCode, UListener, UMessage, UMessageBuilder, UMessageType,
+    UStatus, UTransport, UUri,
+};
+
+const TOPIC: &str = "//conformance-vehicle/A100/1/8001";
+const OTHER_TOPIC: &str = "//conformance-vehicle/A100/1/8002";
+const NOTIFICATION_DESTINATION: &str = "//conformance-vehicle/B100/1/0";
+
+/// A violation of the Transport Layer specification detected by a [`ConformanceSuite`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConformanceFailure {
+    /// The name of the check that has failed.
+    pub check: &'static str,
+    /// A description of the transport's behavior that violates the specification.
+    pub reason: String,
+}
+
+impl std::fmt::Display for ConformanceFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.check, self.reason)
+    }
+}
+
+/// The individual checks performed by a [`ConformanceSuite`].
+#[derive(Clone, Copy, Debug)]
+enum Check {
+    InvalidFilterCriteria,
+    DuplicateRegistration,
+    UnknownRegistration,
+    EmptySinkFilterMatchesPublish,
+    EmptySinkFilterDoesNotMatchNotification,
+    WildcardSourceFilter,
+    NonMatchingSourceFilter,
+    UnregisteredListener,
+}
+
+impl Check {
+    const ALL: [Check; 8] = [
+        Check::InvalidFilterCriteria,
+        Check::DuplicateRegistration,
+        Check::UnknownRegistration,
+        Check::EmptySinkFilterMatchesPublish,
+        Check::EmptySinkFilterDoesNotMatchNotification,
+        Check::WildcardSourceFilter,
+        Check::NonMatchingSourceFilter,
+        Check::UnregisteredListener,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Check::InvalidFilterCriteria => "invalid filter criteria are rejected",
+            Check::DuplicateRegistration => "duplicate registration fails with ALREADY_EXISTS",
+            Check::UnknownRegistration => "unregistering unknown listener fails with NOT_FOUND",
+            Check::EmptySinkFilterMatchesPublish => "empty sink filter matches publish messages",
+            Check::EmptySinkFilterDoesNotMatchNotification => {
+                "empty sink filter does not match notification messages"
+            }
+            Check::WildcardSourceFilter => "wildcard source filter matches any source",
+            Check::NonMatchingSourceFilter => "non-matching source filter is not invoked",
+            Check::UnregisteredListener => "unregistered listener is not invoked",
+        }
+    }
+
+    async fn run(&self, context: &CheckContext) -> Result<(), String> {
+        match self {
+            Check::InvalidFilterCriteria => check_invalid_filter_criteria(context).await,
+            Check::DuplicateRegistration => check_duplicate_registration(context).await,
+            Check::UnknownRegistration => check_unknown_registration(context).await,
+            Check::EmptySinkFilterMatchesPublish => {
+                check_empty_sink_filter_matches_publish(context).await
+            }
+            Check::EmptySinkFilterDoesNotMatchNotification => {
+                check_empty_sink_filter_does_not_match_notification(context).await
+            }
+            Check::WildcardSourceFilter => check_wildcard_source_filter(context).await,
+            Check::NonMatchingSourceFilter => check_non_matching_source_filter(context).await,
+            Check::UnregisteredListener => check_unregistered_listener(context).await,
+        }
+    }
+}
+
+/// A suite of checks that verify a [`UTransport`]'s compliance with the Transport Layer specification.
+///
+/// Each check is run against a new transport instance created by the factory that the suite has been
+/// created for. The checks register listeners with the transport and send messages via the same transport
+/// instance, expecting the messages to be delivered to the listeners.
+///
+/// Checks that involve message types or delivery methods which the transport explicitly states not to
+/// [support](UTransport::capabilities) are skipped.
+pub struct ConformanceSuite<F> {
+    factory: F,
+    delivery_timeout: Duration,
+    quiet_period: Duration,
+}
+
+impl<F, Fut> ConformanceSuite<F>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Arc<dyn UTransport>>,
+{
+    /// Creates a new suite.
+    ///
+    /// # Arguments
+    ///
+    /// * `factory` - The function to invoke for creating the transport instance to run a check against.
+    pub fn new(factory: F) -> Self {
+        ConformanceSuite {
+            factory,
+            delivery_timeout: Duration::from_secs(1),
+            quiet_period: Duration::from_millis(100),
+        }
+    }
+
+    /// Sets the time to wait for a message to be delivered to a listener.
+    ///
+    /// The default value is one second.
+    pub fn with_delivery_timeout(mut self, delivery_timeout: Duration) -> Self {
+        self.delivery_timeout = delivery_timeout;
+        self
+    }
+
+    /// Sets the time to wait for verifying that a message is _not_ being delivered to a listener.
+    ///
+    /// The default value is 100ms.
+    pub fn with_quiet_period(mut self, quiet_period: Duration) -> Self {
+        self.quiet_period = quiet_period;
+        self
+    }
+
+    /// Runs all checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns the failures of all checks that the transport has not passed.
+    pub async fn run(&self) -> Result<(), Vec<ConformanceFailure>> {
+        let mut failures = vec![];
+        for check in Check::ALL {
+            let context = CheckContext {
+                transport: (self.factory)().await,
+                delivery_timeout: self.delivery_timeout,
+                quiet_period: self.quiet_period,
+            };
+            if let Err(reason) = check.run(&context).await {
+                failures.push(ConformanceFailure {
+                    check: check.name(),
+                    reason,
+                });
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    /// Runs all checks, panicking if the transport fails any of them.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message listing all failed checks.
+    pub async fn assert_conformance(&self) {
+        if let Err(failures) = self.run().await {
+            let report = failures
+                .iter()
+                .map(ConformanceFailure::to_string)
+                .collect::<Vec<String>>()
+                .join("\n");
+            panic!("transport does not conform to specification:\n{report}");
+        }
+    }
+}
+
+/// The transport to run a check against and the parameters of the suite.
+struct CheckContext {
+    transport: Arc<dyn UTransport>,
+    delivery_timeout: Duration,
+    quiet_period: Duration,
+}
+
+impl CheckContext {
+    fn supports_push_delivery(&self) -> bool {
+        self.transport.capabilities().supports_push_delivery() != Some(false)
+    }
+
+    fn supports_message_type(&self, message_type: UMessageType) -> bool {
+        self.supports_push_delivery()
+            && self
+                .transport
+                .capabilities()
+                .supports_message_type(message_type)
+                != Some(false)
+    }
+
+    async fn register(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), String> {
+        self.transport
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+            .map_err(|e| {
+                format!(
+                    "failed to register listener for source filter {} and sink filter {:?}: {}",
+                    source_filter.to_uri(false),
+                    sink_filter.map(|uri| uri.to_uri(false)),
+                    e.get_message()
+                )
+            })
+    }
+
+    async fn send(&self, message: UMessage) -> Result<(), String> {
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| format!("failed to send message: {}", e.get_message()))
+    }
+
+    async fn expect_delivery(
+        &self,
+        receiver: &mut mpsc::UnboundedReceiver<UMessage>,
+    ) -> Result<(), String> {
+        match tokio::time::timeout(self.delivery_timeout, receiver.recv()).await {
+            Ok(Some(_msg)) => Ok(()),
+            _ => Err("message has not been delivered to matching listener".to_string()),
+        }
+    }
+
+    async fn expect_no_delivery(
+        &self,
+        receiver: &mut mpsc::UnboundedReceiver<UMessage>,
+    ) -> Result<(), String> {
+        match tokio::time::timeout(self.quiet_period, receiver.recv()).await {
+            Ok(Some(_msg)) => {
+                Err("message has been delivered to non-matching listener".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn uri(uri: &str) -> UUri {
+    UUri::try_from(uri).expect("invalid URI")
+}
+
+fn publish_message(topic: &str) -> UMessage {
+    UMessageBuilder::publish(uri(topic))
+        .build()
+        .expect("failed to create publish message")
+}
+
+fn expect_error_code(
+    result: Result<(), UStatus>,
+    expected: UCode,
+    action: &str,
+) -> Result<(), String> {
+    match result {
+        Err(e) if e.get_code() == expected => Ok(()),
+        Err(e) => Err(format!(
+            "{action} failed with {:?} instead of {expected:?}",
+            e.get_code()
+        )),
+        Ok(()) => Err(format!(
+            "{action} succeeded instead of failing with {expected:?}"
+        )),
+    }
+}
+
+async fn check_invalid_filter_criteria(context: &CheckContext) -> Result<(), String> {
+    let invalid_filters = [
+        // source and sink filters both have resource ID 0
+        (
+            "//conformance-vehicle/A100/1/0",
+            Some(NOTIFICATION_DESTINATION),
+        ),
+        // sink filter matches RPC method but source filter has topic resource ID
+        (TOPIC, Some("//conformance-vehicle/B100/1/1")),
+        // sink filter is empty but source filter has RPC method resource ID
+        ("//conformance-vehicle/A100/1/1", None),
+    ];
+    for (source_filter, sink_filter) in invalid_filters {
+        let (listener, _rx) = forwarding_listener();
+        let sink_filter = sink_filter.map(uri);
+        let result = context
+            .transport
+            .register_listener(&uri(source_filter), sink_filter.as_ref(), listener)
+            .await;
+        expect_error_code(
+            result,
+            UCode::INVALID_ARGUMENT,
+            &format!(
+                "registering listener for source filter {source_filter} and sink filter {:?}",
+                sink_filter.map(|uri| uri.to_uri(false))
+            ),
+        )?;
+    }
+    Ok(())
+}
+
+async fn check_duplicate_registration(context: &CheckContext) -> Result<(), String> {
+    let (listener, _rx) = forwarding_listener();
+    context
+        .register(&uri(TOPIC), None, listener.clone())
+        .await?;
+    let result = context
+        .transport
+        .register_listener(&uri(TOPIC), None, listener)
+        .await;
+    expect_error_code(
+        result,
+        UCode::ALREADY_EXISTS,
+        "registering the same listener twice",
+    )
+}
+
+async fn check_unknown_registration(context: &CheckContext) -> Result<(), String> {
+    let (listener, _rx) = forwarding_listener();
+    let result = context
+        .transport
+        .unregister_listener(&uri(TOPIC), None, listener.clone())
+        .await;
+    expect_error_code(
+        result,
+        UCode::NOT_FOUND,
+        "unregistering a listener that has never been registered",
+    )?;
+
+    context
+        .register(&uri(TOPIC), None, listener.clone())
+        .await?;
+    context
+        .transport
+        .unregister_listener(&uri(TOPIC), None, listener.clone())
+        .await
+        .map_err(|e| format!("failed to unregister listener: {}", e.get_message()))?;
+    let result = context
+        .transport
+        .unregister_listener(&uri(TOPIC), None, listener)
+        .await;
+    expect_error_code(
+        result,
+        UCode::NOT_FOUND,
+        "unregistering a listener that has already been unregistered",
+    )
+}
+
+async fn check_empty_sink_filter_matches_publish(context: &CheckContext) -> Result<(), String> {
+    if !context.supports_message_type(UMessageType::UMESSAGE_TYPE_PUBLISH) {
+        return Ok(());
+    }
+    let (listener, mut rx) = forwarding_listener();
+    context.register(&uri(TOPIC), None, listener).await?;
+    context.send(publish_message(TOPIC)).await?;
+    context.expect_delivery(&mut rx).await
+}
+
+async fn check_empty_sink_filter_does_not_match_notification(
+    context: &CheckContext,
+) -> Result<(), String> {
+    if !context.supports_message_type(UMessageType::UMESSAGE_TYPE_NOTIFICATION) {
+        return Ok(());
+    }
+    let (publish_listener, mut publish_rx) = forwarding_listener();
+    context
+        .register(&uri(TOPIC), None, publish_listener)
+        .await?;
+    let (notification_listener, mut notification_rx) = forwarding_listener();
+    context
+        .register(
+            &uri(TOPIC),
+            Some(&uri(NOTIFICATION_DESTINATION)),
+            notification_listener,
+        )
+        .await?;
+
+    let notification = UMessageBuilder::notification(uri(TOPIC), uri(NOTIFICATION_DESTINATION))
+        .build()
+        .map_err(|e| format!("failed to create notification message: {e}"))?;
+    context.send(notification).await?;
+    context.expect_delivery(&mut notification_rx).await?;
+    context.expect_no_delivery(&mut publish_rx).await
+}
+
+async fn check_wildcard_source_filter(context: &CheckContext) -> Result<(), String> {
+    if !context.supports_message_type(UMessageType::UMESSAGE_TYPE_PUBLISH) {
+        return Ok(());
+    }
+    let (listener, mut rx) = forwarding_listener();
+    context.register(&UUri::any(), None, listener).await?;
+    context.send(publish_message(TOPIC)).await?;
+    context.expect_delivery(&mut rx).await
+}
+
+async fn check_non_matching_source_filter(context: &CheckContext) -> Result<(), String> {
+    if !context.supports_message_type(UMessageType::UMESSAGE_TYPE_PUBLISH) {
+        return Ok(());
+    }
+    let (listener, mut rx) = forwarding_listener();
+    context.register(&uri(OTHER_TOPIC), None, listener).await?;
+    context.send(publish_message(TOPIC)).await?;
+    context.expect_no_delivery(&mut rx).await
+}
+
+async fn check_unregistered_listener(context: &CheckContext) -> Result<(), String> {
+    if !context.supports_message_type(UMessageType::UMESSAGE_TYPE_PUBLISH) {
+        return Ok(());
+    }
+    let (listener, mut rx) = forwarding_listener();
+    context
+        .register(&uri(TOPIC), None, listener.clone())
+        .await?;
+    context
+        .transport
+        .unregister_listener(&uri(TOPIC), None, listener)
+        .await
+        .map_err(|e| format!("failed to unregister listener: {}", e.get_message()))?;
+    context.send(publish_message(TOPIC)).await?;
+    context.expect_no_delivery(&mut rx).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::utransport::MockTransport;
+
+    #[tokio::test]
+    async fn test_suite_reports_failed_checks() {
+        // a transport that accepts all registrations but never delivers any messages
+        let suite = ConformanceSuite::new(|| async {
+            let mut transport = MockTransport::new();
+            transport
+                .expect_do_register_listener()
+                .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+            transport
+                .expect_do_unregister_listener()
+                .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+            transport.expect_do_send().returning(|_msg| Ok(()));
+            Arc::new(transport) as Arc<dyn UTransport>
+        })
+        .with_delivery_timeout(Duration::from_millis(10))
+        .with_quiet_period(Duration::from_millis(10));
+
+        let failures = suite.run().await.unwrap_err();
+        let failed_checks: Vec<&str> = failures.iter().map(|failure| failure.check).collect();
+        assert_eq!(
+            failed_checks,
+            vec![
+                Check::InvalidFilterCriteria.name(),
+                Check::DuplicateRegistration.name(),
+                Check::UnknownRegistration.name(),
+                Check::EmptySinkFilterMatchesPublish.name(),
+                Check::EmptySinkFilterDoesNotMatchNotification.name(),
+                Check::WildcardSourceFilter.name(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,548 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which injects faults into the message exchange of another transport.
+
+The [`FaultInjectingTransport`] can be used for verifying that uEntities cope with unreliable
+transports, e.g. for testing the timeout handling of an `RpcClient` or its handling of duplicate
+response messages.
+*/
+
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tracing::{debug, warn};
+
+use crate::{
+    ConnectionState, ConnectionStateListener, MessageOrdering, TransportCapabilities,
+    TransportStatistics, UCode, UListener, UMessage, UStatus, UTransport, UUri,
+};
+
+/// A failure to inject into invocations of a [`UTransport`] function.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InjectedFailure {
+    /// The code of the error to return.
+    pub code: UCode,
+    /// The probability of an invocation to fail, from 0.0 (never) to 1.0 (always).
+    pub probability: f64,
+}
+
+/// The faults to inject into the message exchange.
+///
+/// All probabilities range from 0.0 (never) to 1.0 (always). The default value does not inject any faults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Faults {
+    /// The probability of a message to be silently discarded instead of being sent.
+    pub drop_probability: f64,
+    /// The probability of a message to be sent twice.
+    pub duplicate_probability: f64,
+    /// The probability of a message to be held back until the next message has been sent.
+    pub reorder_probability: f64,
+    /// The probability of a bit being flipped in a message's payload.
+    /// Messages without payload are not affected.
+    pub corrupt_probability: f64,
+    /// The probability of a message to be sent after a delay.
+    ///
+    /// [`UTransport::send`] returns immediately for delayed messages, i.e. a delayed message
+    /// is also sent after any messages sent in the meantime.
+    pub delay_probability: f64,
+    /// The maximum delay of delayed messages. The actual delay is chosen at random.
+    pub max_delay: Duration,
+    /// The failure to inject into invocations of [`UTransport::send`].
+    pub send_failure: Option<InjectedFailure>,
+    /// The failure to inject into invocations of [`UTransport::register_listener`].
+    pub register_listener_failure: Option<InjectedFailure>,
+}
+
+/// The decisions made for a message being sent.
+struct SendPlan {
+    copies: usize,
+    hold_back: bool,
+    delay: Option<Duration>,
+}
+
+struct FaultState {
+    faults: Faults,
+    rng: StdRng,
+    // the message that has been held back for being sent after the next message
+    held_back: Option<UMessage>,
+}
+
+impl FaultState {
+    fn roll(&mut self, probability: f64) -> bool {
+        probability > 0.0 && self.rng.gen_bool(probability.min(1.0))
+    }
+
+    fn roll_failure(&mut self, failure: Option<InjectedFailure>) -> Result<(), UStatus> {
+        match failure {
+            Some(failure) if self.roll(failure.probability) => Err(UStatus::fail_with_code(
+                failure.code,
+                "failure injected by transport",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn corrupt(&mut self, message: &mut UMessage) {
+        let Some(payload) = message
+            .payload
+            .as_ref()
+            .filter(|payload| !payload.is_empty())
+        else {
+            return;
+        };
+        let mut bytes = payload.to_vec();
+        let index = self.rng.gen_range(0..bytes.len());
+        bytes[index] ^= 1 << self.rng.gen_range(0..8);
+        message.payload = Some(Bytes::from(bytes));
+    }
+}
+
+/// A [`UTransport`] that injects faults into the message exchange via another transport.
+///
+/// Faults are only injected when messages are being sent, i.e. messages that the wrapped transport
+/// delivers to listeners or returns from [`UTransport::receive`] are passed on unchanged. Apart from
+/// that, only failures of [`UTransport::register_listener`] can be injected.
+///
+/// A message that is being held back for reordering is sent along with the next message, when
+/// [`Self::flush`] is invoked or when the transport is dropped, whichever happens first.
+///
+/// The decisions about which faults to inject are made using a pseudo random number generator. Using [`Self::with_seed`], the same sequence
+/// of faults can be injected in subsequent runs.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use up_rust::{
+///     fault_injection::{FaultInjectingTransport, Faults},
+///     local_transport::LocalTransport,
+/// };
+///
+/// // deliver every other message twice, on average
+/// let transport = FaultInjectingTransport::new(
+///     Arc::new(LocalTransport::default()),
+///     Faults {
+///         duplicate_probability: 0.5,
+///         ..Default::default()
+///     },
+/// )
+/// .with_seed(42);
+/// ```
+pub struct FaultInjectingTransport {
+    inner: Arc<dyn UTransport>,
+    state: Mutex<FaultState>,
+}
+
+impl FaultInjectingTransport {
+    /// Creates a new transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The transport to send messages with.
+    /// * `faults` - The faults to inject.
+    pub fn new(inner: Arc<dyn UTransport>, faults: Faults) -> Self {
+        FaultInjectingTransport {
+            inner,
+            state: Mutex::new(FaultState {
+                faults,
+                rng: StdRng::from_entropy(),
+                held_back: None,
+            }),
+        }
+    }
+
+    /// Seeds the pseudo random number generator used for deciding about the faults to inject.
+    pub fn with_seed(self, seed: u64) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.rng = StdRng::seed_from_u64(seed);
+        }
+        self
+    }
+
+    /// Changes the faults to inject from now on.
+    pub fn set_faults(&self, faults: Faults) {
+        if let Ok(mut state) = self.state.lock() {
+            state.faults = faults;
+        }
+    }
+
+    /// Sends the message that has been held back for reordering, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message cannot be sent.
+    pub async fn flush(&self) -> Result<(), UStatus> {
+        let held_back = self
+            .state
+            .lock()
+            .ok()
+            .and_then(|mut state| state.held_back.take());
+        match held_back {
+            Some(message) => self.inner.send(message).await,
+            None => Ok(()),
+        }
+    }
+
+    fn lock_state(&self) -> Result<MutexGuard<'_, FaultState>, UStatus> {
+        self.state.lock().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for fault state")
+        })
+    }
+
+    /// Decides about the faults to inject into sending a message.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the message should be dropped.
+    fn plan_send(&self, message: &mut UMessage) -> Result<Option<SendPlan>, UStatus> {
+        let mut state = self.lock_state()?;
+        let faults = state.faults.clone();
+        state.roll_failure(faults.send_failure)?;
+        if state.roll(faults.drop_probability) {
+            debug!("dropping message");
+            return Ok(None);
+        }
+        if state.roll(faults.corrupt_probability) {
+            debug!("corrupting message payload");
+            state.corrupt(message);
+        }
+        let copies = if state.roll(faults.duplicate_probability) {
+            debug!("duplicating message");
+            2
+        } else {
+            1
+        };
+        let hold_back = state.roll(faults.reorder_probability);
+        let delay = if state.roll(faults.delay_probability) {
+            let max_delay = faults.max_delay.as_nanos() as u64;
+            Some(Duration::from_nanos(state.rng.gen_range(0..=max_delay)))
+        } else {
+            None
+        };
+        Ok(Some(SendPlan {
+            copies,
+            hold_back,
+            delay,
+        }))
+    }
+}
+
+impl Drop for FaultInjectingTransport {
+    fn drop(&mut self) {
+        let Some(message) = self
+            .state
+            .get_mut()
+            .ok()
+            .and_then(|state| state.held_back.take())
+        else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            warn!("discarding message that has been held back for reordering");
+            return;
+        };
+        let inner = self.inner.clone();
+        runtime.spawn(async move {
+            if let Err(e) = inner.send(message).await {
+                warn!("failed to send message that has been held back for reordering: {e}");
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl UTransport for FaultInjectingTransport {
+    /// Sends a message using the wrapped transport, injecting faults as configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns the injected failure or an error if the wrapped transport fails to send the message.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        let mut message = message;
+        let Some(plan) = self.plan_send(&mut message)? else {
+            return Ok(());
+        };
+        let messages = vec![message; plan.copies];
+
+        if let Some(delay) = plan.delay {
+            debug!("delaying message by {delay:?}");
+            let inner = self.inner.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(delay).await;
+                for message in messages {
+                    if let Err(e) = inner.send(message).await {
+                        debug!("failed to send delayed message: {e}");
+                    }
+                }
+            });
+            return Ok(());
+        }
+
+        let held_back = {
+            let mut state = self.lock_state()?;
+            if plan.hold_back && state.held_back.is_none() {
+                debug!("holding back message");
+                // duplicates are not held back
+                state.held_back = messages.into_iter().next();
+                return Ok(());
+            }
+            state.held_back.take()
+        };
+        for message in messages {
+            self.inner.send(message).await?;
+        }
+        match held_back {
+            Some(message) => self.inner.send(message).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Gets the features supported by the wrapped transport.
+    ///
+    /// No ordering guarantees are given, because messages may get reordered or delayed.
+    fn capabilities(&self) -> TransportCapabilities {
+        self.inner
+            .capabilities()
+            .with_ordering(MessageOrdering::Unordered)
+    }
+
+    fn statistics(&self) -> Option<TransportStatistics> {
+        self.inner.statistics()
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        self.inner.connection_state()
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.inner.register_connection_state_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.inner.unregister_connection_state_listener(listener)
+    }
+
+    async fn receive(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<UMessage, UStatus> {
+        self.inner.receive(source_filter, sink_filter).await
+    }
+
+    /// Registers a listener with the wrapped transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns the injected failure or an error if the wrapped transport fails to register the listener.
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        {
+            let mut state = self.lock_state()?;
+            let failure = state.faults.register_listener_failure;
+            state.roll_failure(failure)?;
+        }
+        self.inner
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.inner
+            .unregister_listener(source_filter, sink_filter, listener)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio::sync::mpsc;
+
+    use crate::{
+        local_transport::LocalTransport, utransport::forwarding_listener, UMessageBuilder,
+        UPayloadFormat,
+    };
+
+    fn topic() -> UUri {
+        UUri::try_from("//my-vehicle/A100/1/8001").unwrap()
+    }
+
+    fn message(index: u8) -> UMessage {
+        UMessageBuilder::publish(topic())
+            .build_with_payload(vec![index], UPayloadFormat::UPAYLOAD_FORMAT_RAW)
+            .unwrap()
+    }
+
+    async fn transport_with_listener(
+        faults: Faults,
+    ) -> (FaultInjectingTransport, mpsc::UnboundedReceiver<UMessage>) {
+        let transport = FaultInjectingTransport::new(Arc::new(LocalTransport::default()), faults)
+            .with_seed(0x1234);
+        let (listener, rx) = forwarding_listener();
+        transport
+            .register_listener(&topic(), None, listener)
+            .await
+            .unwrap();
+        (transport, rx)
+    }
+
+    fn payloads(rx: &mut mpsc::UnboundedReceiver<UMessage>) -> Vec<u8> {
+        std::iter::from_fn(|| rx.try_recv().ok())
+            .map(|msg| msg.payload.unwrap()[0])
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_dropped_and_duplicated() {
+        let (transport, mut rx) = transport_with_listener(Faults {
+            drop_probability: 1.0,
+            ..Default::default()
+        })
+        .await;
+        transport.send(message(1)).await.unwrap();
+        assert!(payloads(&mut rx).is_empty());
+
+        transport.set_faults(Faults {
+            duplicate_probability: 1.0,
+            ..Default::default()
+        });
+        transport.send(message(2)).await.unwrap();
+        assert_eq!(payloads(&mut rx), vec![2, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_reordered() {
+        let (transport, mut rx) = transport_with_listener(Faults {
+            reorder_probability: 1.0,
+            ..Default::default()
+        })
+        .await;
+        for index in 1..=5 {
+            transport.send(message(index)).await.unwrap();
+        }
+        transport.flush().await.unwrap();
+        assert_eq!(payloads(&mut rx), vec![2, 1, 4, 3, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_held_back_message_is_sent_on_drop() {
+        let (transport, mut rx) = transport_with_listener(Faults {
+            reorder_probability: 1.0,
+            ..Default::default()
+        })
+        .await;
+        transport.send(message(1)).await.unwrap();
+        assert!(payloads(&mut rx).is_empty());
+
+        drop(transport);
+
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap();
+        assert_eq!(received.and_then(|msg| msg.payload), Some(vec![1].into()));
+    }
+
+    #[tokio::test]
+    async fn test_message_payload_is_corrupted() {
+        let (transport, mut rx) = transport_with_listener(Faults {
+            corrupt_probability: 1.0,
+            ..Default::default()
+        })
+        .await;
+        transport.send(message(0)).await.unwrap();
+        assert_eq!(payloads(&mut rx)[0].count_ones(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_messages_are_delayed() {
+        let (transport, mut rx) = transport_with_listener(Faults {
+            delay_probability: 1.0,
+            max_delay: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .await;
+        transport.send(message(1)).await.unwrap();
+        assert!(payloads(&mut rx).is_empty());
+        let received = tokio::time::timeout(Duration::from_millis(101), rx.recv()).await;
+        assert!(received.is_ok_and(|msg| msg.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_seeded_faults_are_reproducible() {
+        let faults = Faults {
+            drop_probability: 0.5,
+            ..Default::default()
+        };
+        let mut outcomes = vec![];
+        for _run in 0..2 {
+            let (transport, mut rx) = transport_with_listener(faults.clone()).await;
+            for index in 0..20 {
+                transport.send(message(index)).await.unwrap();
+            }
+            outcomes.push(payloads(&mut rx));
+        }
+        assert!(outcomes[0].len() < 20);
+        assert_eq!(outcomes[0], outcomes[1]);
+    }
+
+    #[tokio::test]
+    async fn test_failures_are_injected() {
+        let transport = FaultInjectingTransport::new(
+            Arc::new(LocalTransport::default()),
+            Faults {
+                send_failure: Some(InjectedFailure {
+                    code: UCode::UNAVAILABLE,
+                    probability: 1.0,
+                }),
+                register_listener_failure: Some(InjectedFailure {
+                    code: UCode::RESOURCE_EXHAUSTED,
+                    probability: 1.0,
+                }),
+                ..Default::default()
+            },
+        );
+        let (listener, _rx) = forwarding_listener();
+        assert!(transport
+            .send(message(1))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::UNAVAILABLE));
+        assert!(transport
+            .register_listener(&topic(), None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+    }
+}
@@ -40,6 +40,8 @@ For user convenience, all of these modules export their types on up_rust top-lev
 * `cloudevents` enables support for mapping UMessages to/from CloudEvents using Protobuf Format according to the
   [uProtocol specification](https://github.com/eclipse-uprotocol/up-spec/blob/v1.6.0-alpha.4/up-l1/cloudevents.adoc).
 
+* `config` enables creating a `StaticUriProvider` from a TOML or JSON file containing the uEntity's authority,
+  entity ID and major version.
 * `communication` enables support for the [Communication Layer API](https://github.com/eclipse-uprotocol/up-spec/blob/v1.6.0-alpha.4/up-l2/api.adoc) and its
   default implementation on top of the [Transport Layer API](https://github.com/eclipse-uprotocol/up-spec/blob/v1.6.0-alpha.4/up-l1/README.adoc).
   Enabled by default.
@@ -49,8 +51,30 @@ For user convenience, all of these modules export their types on up_rust top-lev
   implementations. Enabled by default.
 * `utwin` enables support for types required to interact with [uTwin service](https://raw.githubusercontent.com/eclipse-uprotocol/up-spec/v1.6.0-alpha.4/up-l3/utwin/v3/README.adoc)
   implementations.
+* `uds` provides a UTransport and a corresponding broker for exchanging messages between processes on the same host
+  by means of Unix domain sockets. Only available on Unix platforms.
+* `tcp` provides a UTransport and a corresponding hub for exchanging messages by means of TCP connections.
+  The transport automatically re-establishes lost connections to the hub.
+* `shm` provides a UTransport for exchanging messages between processes on the same host by means of a
+  shared memory ring buffer. Payloads of received messages reference the shared memory directly. Only available on Linux.
+* `udp` provides a UTransport for distributing Publish messages to UDP multicast groups.
 * `test-util` provides some useful mock implementations for testing. In particular, provides mock implementations of UTransport and Communication Layer API traits which make implementing unit tests a lot easier.
+  The `RecordingTransport` records all sent messages and registered listeners, provides assertion helpers for expecting
+  particular messages to be sent and can inject inbound messages into the registered listeners.
+  In combination with the `communication` feature, the `FakeRpcService` answers RPC requests with scripted responses,
+  errors or delays and records the requests that it has received.
+  Also provides a suite of conformance tests that UTransport implementations can run for verifying their compliance
+  with the Transport Layer specification. In combination with the `util` feature, also provides a harness for running tests
+  in virtual time, which allows verifying timeouts and the expiry of messages deterministically. Multiple authorities
+  can be connected by means of a simulated network with configurable latency, bandwidth, partitions and packet loss.
 * `util` provides some useful helper structs. In particular, provides a local, in-memory UTransport for exchanging messages within a single process. This transport is also used by the examples illustrating usage of the Communication Layer API.
+  Also provides means for adding cross-cutting behavior like logging or validation of messages to existing UTransport implementations
+  and for capturing and replaying the messages exchanged via a UTransport. For testing purposes, a UTransport
+  wrapper is provided that injects faults like lost, duplicate or delayed messages. Received messages can be consumed
+  as a `Stream` by means of `MessageStream`. A UTransport wrapper collects statistics about the messages exchanged
+  via transports that do not collect any statistics themselves. A uStreamer forwards messages between uEntities that are connected to
+  different UTransports, and a composite UTransport delegates to different UTransports depending on the
+  authority that messages are exchanged with.
 
 ## References
 
@@ -64,11 +88,40 @@ mod cloudevents;
 #[cfg(feature = "cloudevents")]
 pub use cloudevents::{CloudEvent, CONTENT_TYPE_CLOUDEVENTS_PROTOBUF};
 
+#[cfg(feature = "util")]
+pub mod capture;
+mod clock;
+
 #[cfg(feature = "communication")]
 pub mod communication;
+#[cfg(feature = "util")]
+pub mod composite_transport;
+#[cfg(any(test, feature = "test-util"))]
+pub mod conformance;
 
+#[cfg(feature = "util")]
+pub mod fault_injection;
 #[cfg(feature = "util")]
 pub mod local_transport;
+#[cfg(feature = "util")]
+pub mod middleware;
+
+#[cfg(all(feature = "shm", target_os = "linux"))]
+pub mod shm_transport;
+#[cfg(all(any(test, feature = "test-util"), feature = "util"))]
+pub mod simulation;
+#[cfg(any(all(feature = "uds", unix), feature = "tcp"))]
+mod socket_transport;
+#[cfg(feature = "util")]
+pub mod statistics_transport;
+#[cfg(feature = "tcp")]
+pub mod tcp_transport;
+#[cfg(feature = "udp")]
+pub mod udp_transport;
+#[cfg(all(feature = "uds", unix))]
+pub mod uds_transport;
+#[cfg(feature = "util")]
+pub mod ustreamer;
 
 mod uattributes;
 pub use uattributes::{
@@ -87,12 +140,20 @@ mod ustatus;
 pub use ustatus::{UCode, UStatus};
 
 mod utransport;
+#[cfg(feature = "util")]
+pub use utransport::MessageStream;
+#[cfg(any(test, feature = "test-util"))]
+pub use utransport::RecordingTransport;
 pub use utransport::{
-    verify_filter_criteria, ComparableListener, LocalUriProvider, StaticUriProvider, UListener,
-    UTransport,
+    verify_filter_criteria, ComparableListener, ConnectionState, ConnectionStateListener,
+    ConnectionStateNotifier, FilterIndex, LatencyStatistics, LocalUriProvider, MessageCounters,
+    MessageOrdering, PriorityScheduler, StaticUriProvider, StatisticsRecorder,
+    TransportCapabilities, TransportStatistics, UListener, UTransport, UriProviderConfigError,
 };
 #[cfg(feature = "test-util")]
-pub use utransport::{MockLocalUriProvider, MockTransport, MockUListener};
+pub use utransport::{
+    MockConnectionStateListener, MockLocalUriProvider, MockTransport, MockUListener,
+};
 
 mod uuid;
 pub use uuid::UUID;
@@ -16,98 +16,771 @@ Provides a local UTransport which can be used for connecting uEntities running i
 process.
 */
 
-use std::{collections::HashSet, sync::Arc};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::{Hash, Hasher},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
 
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
+use tracing::debug;
 
-use crate::{ComparableListener, UListener, UMessage, UStatus, UTransport, UUri};
+use crate::{
+    utransport::{FilterIndex, PriorityScheduler, StatisticsRecorder},
+    verify_filter_criteria, ComparableListener, ConnectionState, ConnectionStateListener,
+    MessageOrdering, TransportCapabilities, TransportStatistics, UAttributesValidators, UCode,
+    UListener, UMessage, UMessageType, UStatus, UTransport, UUri,
+};
 
-#[derive(Eq, PartialEq, Hash)]
-struct RegisteredListener {
-    source_filter: UUri,
-    sink_filter: Option<UUri>,
-    listener: ComparableListener,
+/// The policy to apply when a message is dispatched to a listener whose queue is already full.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Discards the oldest message of the lowest priority in order to make room for the new message.
+    ///
+    /// The discarded message might be the new message itself, if all queued messages have a higher priority.
+    #[default]
+    DropOldest,
+    /// Discards the new message, keeping the listener's queue unchanged.
+    DropNewest,
+    /// Discards the new message and lets [`UTransport::send`] fail with a [`UCode::RESOURCE_EXHAUSTED`].
+    Fail,
 }
 
-impl RegisteredListener {
-    fn matches(&self, source: &UUri, sink: Option<&UUri>) -> bool {
-        if !self.source_filter.matches(source) {
-            return false;
+/// The mechanism used by a [`LocalTransport`] for dispatching messages to its listeners.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Matching listeners are invoked one after the other from within [`UTransport::send`].
+    ///
+    /// `send` returns once all matching listeners have processed the message.
+    #[default]
+    Sequential,
+    /// Each listener is served by a dedicated worker task that processes messages from
+    /// a bounded queue.
+    ///
+    /// [`UTransport::send`] only puts the message into the queues of the matching listeners and
+    /// returns immediately, i.e. a slow listener does not block the sender nor any of the other listeners.
+    /// Messages are delivered to a particular listener in the order of their [priority](crate::UPriority),
+    /// messages of the same priority are delivered in the order in which they have been sent.
+    Queued {
+        /// The maximum number of messages waiting to be processed by a listener.
+        /// A value of 0 is treated as 1.
+        queue_depth: usize,
+        /// The policy to apply when a listener's queue is full.
+        overflow_policy: OverflowPolicy,
+    },
+}
+
+/// A bounded queue of messages waiting to be processed by a single listener.
+///
+/// Messages are taken out of the queue in the order of their priority.
+struct ListenerQueue {
+    messages: Mutex<PriorityScheduler>,
+    capacity: usize,
+    overflow_policy: OverflowPolicy,
+    message_available: Notify,
+    closed: AtomicBool,
+    // records the messages discarded due to the overflow policy
+    statistics: Arc<StatisticsRecorder>,
+}
+
+impl ListenerQueue {
+    fn new(
+        queue_depth: usize,
+        overflow_policy: OverflowPolicy,
+        statistics: Arc<StatisticsRecorder>,
+    ) -> Self {
+        let capacity = queue_depth.max(1);
+        ListenerQueue {
+            messages: Mutex::new(PriorityScheduler::new()),
+            capacity,
+            overflow_policy,
+            message_available: Notify::new(),
+            closed: AtomicBool::new(false),
+            statistics,
         }
+    }
 
-        if let Some(pattern) = &self.sink_filter {
-            sink.is_some_and(|candidate_sink| pattern.matches(candidate_sink))
+    /// Puts a message into this queue, applying the overflow policy if the queue is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the queue is full and the overflow policy is [`OverflowPolicy::Fail`].
+    fn push(&self, message: UMessage) -> Result<(), UStatus> {
+        let Ok(mut messages) = self.messages.lock() else {
+            return Err(UStatus::fail_with_code(
+                UCode::INTERNAL,
+                "failed to acquire lock for listener queue",
+            ));
+        };
+        if messages.len() >= self.capacity {
+            match self.overflow_policy {
+                OverflowPolicy::DropOldest => {
+                    debug!("listener queue is full, discarding oldest message of lowest priority");
+                    messages.push(message);
+                    if let Some(discarded) = messages.pop_lowest() {
+                        self.statistics.record_dropped(&discarded);
+                    }
+                }
+                OverflowPolicy::DropNewest => {
+                    debug!("listener queue is full, discarding new message");
+                    self.statistics.record_dropped(&message);
+                    return Ok(());
+                }
+                OverflowPolicy::Fail => {
+                    self.statistics.record_dropped(&message);
+                    return Err(UStatus::fail_with_code(
+                        UCode::RESOURCE_EXHAUSTED,
+                        "listener queue is full",
+                    ));
+                }
+            }
         } else {
-            sink.is_none()
+            messages.push(message);
         }
+        drop(messages);
+        self.message_available.notify_one();
+        Ok(())
     }
-    fn matches_msg(&self, msg: &UMessage) -> bool {
-        if let Some(source) = msg
-            .attributes
-            .as_ref()
-            .and_then(|attribs| attribs.source.as_ref())
-        {
-            self.matches(
-                source,
-                msg.attributes
-                    .as_ref()
-                    .and_then(|attribs| attribs.sink.as_ref()),
-            )
-        } else {
-            false
+
+    /// Takes the next message from this queue, waiting for a message to become available if necessary.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the queue has been closed.
+    async fn pop(&self) -> Option<UMessage> {
+        loop {
+            // register for notifications before checking the queue's state, so that
+            // a message being pushed or the queue being closed in between is not missed
+            let mut notified = std::pin::pin!(self.message_available.notified());
+            notified.as_mut().enable();
+            if self.closed.load(Ordering::Acquire) {
+                return None;
+            }
+            if let Some(msg) = self.try_pop() {
+                return Some(msg);
+            }
+            notified.await;
         }
     }
-    async fn on_receive(&self, msg: UMessage) {
-        self.listener.on_receive(msg).await
+
+    /// Takes the next message from this queue without waiting.
+    fn try_pop(&self) -> Option<UMessage> {
+        self.messages
+            .lock()
+            .ok()
+            .and_then(|mut messages| messages.pop())
+    }
+
+    /// Discards all pending messages and stops the worker task processing this queue.
+    fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        if let Ok(mut messages) = self.messages.lock() {
+            messages.clear();
+        }
+        // wake up all tasks waiting for a message
+        self.message_available.notify_waiters();
     }
 }
 
+/// A listener that has been registered with a [`LocalTransport`].
+struct RegisteredListener {
+    listener: ComparableListener,
+    // the queue that the listener consumes messages from, if dispatch mode is Queued
+    queue: Option<Arc<ListenerQueue>>,
+}
+
+impl Hash for RegisteredListener {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.listener.hash(state);
+    }
+}
+
+impl PartialEq for RegisteredListener {
+    fn eq(&self, other: &Self) -> bool {
+        self.listener == other.listener
+    }
+}
+
+impl Eq for RegisteredListener {}
+
+impl RegisteredListener {
+    fn new(listener: Arc<dyn UListener>) -> Self {
+        RegisteredListener {
+            listener: ComparableListener::new(listener),
+            queue: None,
+        }
+    }
+}
+
+/// Checks if a message's source and sink match the given filter patterns.
+fn matches_filters(source_filter: &UUri, sink_filter: Option<&UUri>, msg: &UMessage) -> bool {
+    let Some(source) = msg.source() else {
+        return false;
+    };
+    if !source_filter.matches(source) {
+        return false;
+    }
+
+    if let Some(pattern) = sink_filter {
+        msg.sink()
+            .is_some_and(|candidate_sink| pattern.matches(candidate_sink))
+    } else {
+        msg.sink().is_none()
+    }
+}
+
+/// Checks if a message's attributes are consistent with its type and if the message has not expired yet.
+///
+/// # Errors
+///
+/// Returns an error with
+/// * [`UCode::INVALID_ARGUMENT`] if the message's attributes are invalid,
+/// * [`UCode::DEADLINE_EXCEEDED`] if the message's TTL has expired.
+pub(crate) fn check_message(message: &UMessage) -> Result<(), UStatus> {
+    let Some(attributes) = message.attributes.as_ref() else {
+        return Err(UStatus::fail_with_code(
+            UCode::INVALID_ARGUMENT,
+            "message has no attributes",
+        ));
+    };
+    UAttributesValidators::get_validator_for_attributes(attributes)
+        .validate(attributes)
+        .map_err(|e| UStatus::fail_with_code(UCode::INVALID_ARGUMENT, e.to_string()))?;
+    attributes
+        .check_expired()
+        .map_err(|e| UStatus::fail_with_code(UCode::DEADLINE_EXCEEDED, e.to_string()))
+}
+
+/// Checks if a message may be delivered to a listener.
+///
+/// In strict mode, messages that fail the [checks](check_message) are discarded.
+/// The outcome is recorded in the given statistics.
+fn is_deliverable(message: &UMessage, strict: bool, statistics: &StatisticsRecorder) -> bool {
+    if strict {
+        if let Err(e) = check_message(message) {
+            debug!("discarding message [{}]", e.get_message());
+            statistics.record_dropped(message);
+            return false;
+        }
+    }
+    statistics.record_delivered(message);
+    true
+}
+
+/// The means by which a message reaches a particular listener.
+enum Delivery {
+    Direct(ComparableListener),
+    Queued(Arc<ListenerQueue>),
+}
+
+/// The source and sink filter patterns that a pull buffer has been registered for.
+type PullFilter = (UUri, Option<UUri>);
+
 /// A [`UTransport`] that can be used to exchange messages within a single process.
 ///
 /// A message sent via [`UTransport::send`] will be dispatched to all registered listeners that
-/// match the message's source and sink filters.
+/// match the message's source and sink filters. The way in which listeners are invoked is
+/// determined by the transport's [`DispatchMode`].
+///
+/// The transport also supports the _pull_ delivery method. Messages that match the filters of a
+/// [registered pull filter](Self::register_pull_filter) are buffered until they are being fetched
+/// using [`UTransport::receive`] or [`LocalTransport::receive_timeout`].
+///
+/// By default, the transport dispatches messages as they are. Use [`LocalTransport::with_strict_mode`]
+/// for making the transport behave like a spec-compliant transport that rejects invalid and expired messages.
+///
+/// The transport keeps [statistics](UTransport::statistics) about the messages it has dispatched and the
+/// listeners registered with it.
 #[derive(Default)]
 pub struct LocalTransport {
-    listeners: RwLock<HashSet<RegisteredListener>>,
+    listeners: RwLock<FilterIndex<RegisteredListener>>,
+    pull_buffers: RwLock<HashMap<PullFilter, Arc<ListenerQueue>>>,
+    dispatch_mode: DispatchMode,
+    strict: bool,
+    statistics: Arc<StatisticsRecorder>,
 }
 
 impl LocalTransport {
-    async fn dispatch(&self, message: UMessage) {
-        let listeners = self.listeners.read().await;
-        for listener in listeners.iter() {
-            if listener.matches_msg(&message) {
-                listener.on_receive(message.clone()).await;
+    /// Sets the mechanism to use for dispatching messages to listeners.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::local_transport::{DispatchMode, LocalTransport, OverflowPolicy};
+    ///
+    /// let transport = LocalTransport::default().with_dispatch_mode(DispatchMode::Queued {
+    ///     queue_depth: 100,
+    ///     overflow_policy: OverflowPolicy::DropOldest,
+    /// });
+    /// ```
+    pub fn with_dispatch_mode(mut self, dispatch_mode: DispatchMode) -> Self {
+        self.dispatch_mode = dispatch_mode;
+        self
+    }
+
+    /// Enables or disables strict mode.
+    ///
+    /// In strict mode, the transport verifies that a message's attributes are consistent with
+    /// the message's type using [`UAttributesValidators::get_validator_for_attributes`] and that
+    /// the message has not expired yet using [`UAttributes::check_expired`](crate::UAttributes::check_expired).
+    /// These checks are performed when the message is being sent and again right before the message
+    /// is being delivered to a listener or fetched from a pull buffer. Messages that have expired
+    /// in the meantime are silently discarded.
+    ///
+    /// Listeners and pull filters are only registered if the filters pass [`verify_filter_criteria`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{local_transport::LocalTransport, UCode, UMessageBuilder, UTransport, UUri};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let transport = LocalTransport::default().with_strict_mode(true);
+    /// let method = UUri::try_from_parts("my-vehicle", 0x1000, 0x01, 0x0001).unwrap();
+    /// let reply_to = UUri::try_from_parts("my-vehicle", 0x2000, 0x01, 0x0000).unwrap();
+    ///
+    /// // a request message without a TTL is invalid
+    /// let mut request = UMessageBuilder::request(method, reply_to, 5000).build().unwrap();
+    /// request.attributes.as_mut().unwrap().ttl = None;
+    /// assert!(transport
+    ///     .send(request)
+    ///     .await
+    ///     .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    /// # }
+    /// ```
+    pub fn with_strict_mode(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    async fn dispatch(&self, message: UMessage) -> Result<(), UStatus> {
+        self.dispatch_batch(vec![message]).await
+    }
+
+    /// Dispatches messages to the matching listeners and pull buffers, in the given order.
+    ///
+    /// The locks protecting the listeners and pull buffers are acquired only once for all messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error that occurred while dispatching the messages. All messages will still
+    /// have been dispatched to all other matching listeners.
+    async fn dispatch_batch(&self, messages: Vec<UMessage>) -> Result<(), UStatus> {
+        // collect the matching listeners first so that the lock is not being held
+        // while the listeners process the messages, which would prevent listeners
+        // from (un)registering other listeners
+        messages
+            .iter()
+            .for_each(|message| self.statistics.record_sent(message));
+        let batch: Vec<(UMessage, Vec<Delivery>)> = {
+            let listeners = self.listeners.read().await;
+            messages
+                .into_iter()
+                .map(|message| {
+                    let deliveries = listeners
+                        .find_matches_for_message(&message)
+                        .into_iter()
+                        .map(|listener| match listener.queue.as_ref() {
+                            Some(queue) => Delivery::Queued(queue.clone()),
+                            None => Delivery::Direct(listener.listener.clone()),
+                        })
+                        .collect();
+                    (message, deliveries)
+                })
+                .collect()
+        };
+
+        {
+            let pull_buffers = self.pull_buffers.read().await;
+            for (message, _deliveries) in &batch {
+                pull_buffers
+                    .iter()
+                    .filter(|((source_filter, sink_filter), _buffer)| {
+                        matches_filters(source_filter, sink_filter.as_ref(), message)
+                    })
+                    .for_each(|(_filter, buffer)| {
+                        // pull buffers always drop the oldest message, so this cannot fail
+                        let _ = buffer.push(message.clone());
+                    });
+            }
+        }
+
+        let mut result = Ok(());
+        for (message, deliveries) in batch {
+            for delivery in deliveries {
+                match delivery {
+                    Delivery::Direct(listener) => {
+                        if is_deliverable(&message, self.strict, &self.statistics) {
+                            listener.on_receive(message.clone()).await;
+                        }
+                    }
+                    Delivery::Queued(queue) => {
+                        if let Err(e) = queue.push(message.clone()) {
+                            result = result.and(Err(e));
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Starts buffering messages for retrieval via [`UTransport::receive`].
+    ///
+    /// All messages sent via this transport that match the given filters are kept in a buffer
+    /// until they are being fetched using [`UTransport::receive`] or [`Self::receive_timeout`]
+    /// with the very same filters.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_filter` - The _source_ address pattern that messages need to match.
+    /// * `sink_filter` - The _sink_ address pattern that messages need to match,
+    ///   or `None` to match messages that do not contain any sink address.
+    /// * `buffer_size` - The maximum number of messages to keep. If the buffer is full, the oldest
+    ///   message of the lowest priority is discarded in favor of a newly arriving message.
+    ///   A value of 0 is treated as 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the transport is in [strict mode](LocalTransport::with_strict_mode)
+    ///   and the filters do not pass [`verify_filter_criteria`],
+    /// * [`UCode::ALREADY_EXISTS`] if a pull filter has already been registered for the given filters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{local_transport::LocalTransport, UMessageBuilder, UTransport, UUri};
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let transport = LocalTransport::default();
+    /// let topic = UUri::try_from_parts("my-vehicle", 0x1000, 0x01, 0x9a00).unwrap();
+    /// transport.register_pull_filter(&topic, None, 10).await.unwrap();
+    ///
+    /// let msg = UMessageBuilder::publish(topic.clone()).build().unwrap();
+    /// transport.send(msg.clone()).await.unwrap();
+    /// assert_eq!(transport.receive(&topic, None).await.unwrap(), msg);
+    /// # }
+    /// ```
+    pub async fn register_pull_filter(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        buffer_size: usize,
+    ) -> Result<(), UStatus> {
+        if self.strict {
+            verify_filter_criteria(source_filter, sink_filter)?;
+        }
+        let mut pull_buffers = self.pull_buffers.write().await;
+        match pull_buffers.entry((source_filter.to_owned(), sink_filter.cloned())) {
+            Entry::Occupied(_) => Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "pull filter already registered for filters",
+            )),
+            Entry::Vacant(entry) => {
+                entry.insert(Arc::new(ListenerQueue::new(
+                    buffer_size,
+                    OverflowPolicy::DropOldest,
+                    self.statistics.clone(),
+                )));
+                Ok(())
+            }
+        }
+    }
+
+    /// Stops buffering messages for given filters.
+    ///
+    /// All messages that have been buffered for the filters but not yet fetched are discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::NOT_FOUND`] if no pull filter has been registered
+    /// for the given filters.
+    pub async fn unregister_pull_filter(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        let mut pull_buffers = self.pull_buffers.write().await;
+        if let Some(buffer) = pull_buffers.remove(&(source_filter.to_owned(), sink_filter.cloned()))
+        {
+            buffer.close();
+            Ok(())
+        } else {
+            Err(UStatus::fail_with_code(
+                UCode::NOT_FOUND,
+                "no pull filter registered for filters",
+            ))
+        }
+    }
+
+    /// Receives a message, waiting for a matching message to arrive if necessary.
+    ///
+    /// This is the same as [`UTransport::receive`] except that this function waits for a matching message
+    /// to be sent, if no message is buffered at the time of invocation.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_filter` - The _source_ address pattern that the pull filter has been registered for.
+    /// * `sink_filter` - The _sink_ address pattern that the pull filter has been registered for.
+    /// * `timeout` - The maximum amount of time to wait for a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::FAILED_PRECONDITION`] if no pull filter has been registered for the given filters,
+    /// * [`UCode::DEADLINE_EXCEEDED`] if no message has arrived within the given amount of time,
+    /// * [`UCode::CANCELLED`] if the pull filter has been unregistered while waiting for a message.
+    pub async fn receive_timeout(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        timeout: Duration,
+    ) -> Result<UMessage, UStatus> {
+        let buffer = self.get_pull_buffer(source_filter, sink_filter).await?;
+        let next_deliverable = async {
+            while let Some(msg) = buffer.pop().await {
+                if is_deliverable(&msg, self.strict, &self.statistics) {
+                    return Some(msg);
+                }
             }
+            None
+        };
+        match tokio::time::timeout(timeout, next_deliverable).await {
+            Ok(Some(msg)) => Ok(msg),
+            Ok(None) => Err(UStatus::fail_with_code(
+                UCode::CANCELLED,
+                "pull filter has been unregistered",
+            )),
+            Err(_elapsed) => Err(UStatus::fail_with_code(
+                UCode::DEADLINE_EXCEEDED,
+                "no message has arrived in time",
+            )),
         }
     }
+
+    async fn get_pull_buffer(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<Arc<ListenerQueue>, UStatus> {
+        self.pull_buffers
+            .read()
+            .await
+            .get(&(source_filter.to_owned(), sink_filter.cloned()))
+            .cloned()
+            .ok_or_else(|| {
+                UStatus::fail_with_code(
+                    UCode::FAILED_PRECONDITION,
+                    "no pull filter registered for filters",
+                )
+            })
+    }
+
+    fn start_worker(&self, listener: &ComparableListener) -> Option<Arc<ListenerQueue>> {
+        let DispatchMode::Queued {
+            queue_depth,
+            overflow_policy,
+        } = self.dispatch_mode
+        else {
+            return None;
+        };
+        let queue = Arc::new(ListenerQueue::new(
+            queue_depth,
+            overflow_policy,
+            self.statistics.clone(),
+        ));
+        let worker_queue = queue.clone();
+        let worker_listener = listener.clone();
+        let strict = self.strict;
+        let statistics = self.statistics.clone();
+        tokio::spawn(async move {
+            while let Some(msg) = worker_queue.pop().await {
+                if is_deliverable(&msg, strict, &statistics) {
+                    worker_listener.on_receive(msg).await;
+                }
+            }
+        });
+        Some(queue)
+    }
+}
+
+impl Drop for LocalTransport {
+    fn drop(&mut self) {
+        // stop all worker tasks
+        self.listeners
+            .get_mut()
+            .values()
+            .filter_map(|listener| listener.queue.as_ref())
+            .for_each(|queue| queue.close());
+        // and wake up all tasks waiting for messages to be pulled
+        self.pull_buffers
+            .get_mut()
+            .values()
+            .for_each(|buffer| buffer.close());
+    }
 }
 
 #[async_trait::async_trait]
 impl UTransport for LocalTransport {
+    /// Dispatches a message to all registered listeners that match the message's source and sink.
+    ///
+    /// # Errors
+    ///
+    /// In [strict mode](LocalTransport::with_strict_mode), returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the message's attributes are invalid,
+    /// * [`UCode::DEADLINE_EXCEEDED`] if the message has already expired.
+    ///
+    /// Returns an error with [`UCode::RESOURCE_EXHAUSTED`] if the transport uses [`DispatchMode::Queued`]
+    /// with [`OverflowPolicy::Fail`] and the queue of any of the matching listeners is full. The message
+    /// will still have been delivered to all other matching listeners.
     async fn send(&self, message: UMessage) -> Result<(), UStatus> {
-        self.dispatch(message).await;
+        if self.strict {
+            check_message(&message).inspect_err(|_e| self.statistics.record_dropped(&message))?;
+        }
+        self.dispatch(message).await
+    }
+
+    /// Dispatches messages to all registered listeners that match the messages' source and sink.
+    ///
+    /// In contrast to sending the messages one by one, the locks protecting the transport's listeners
+    /// are acquired only once for the whole batch. Messages are dispatched in the given order.
+    ///
+    /// # Errors
+    ///
+    /// In [strict mode](LocalTransport::with_strict_mode), returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if any message's attributes are invalid,
+    /// * [`UCode::DEADLINE_EXCEEDED`] if any message has already expired.
+    ///
+    /// In this case, only the messages preceding the invalid message are dispatched.
+    ///
+    /// Returns an error with [`UCode::RESOURCE_EXHAUSTED`] if the transport uses [`DispatchMode::Queued`]
+    /// with [`OverflowPolicy::Fail`] and the queue of any of the matching listeners is full. All messages
+    /// will still have been delivered to all other matching listeners.
+    async fn send_batch(&self, mut messages: Vec<UMessage>) -> Result<(), UStatus> {
+        let mut check_result = Ok(());
+        if self.strict {
+            if let Some((index, e)) = messages
+                .iter()
+                .enumerate()
+                .find_map(|(index, message)| check_message(message).err().map(|e| (index, e)))
+            {
+                messages[index..]
+                    .iter()
+                    .for_each(|message| self.statistics.record_dropped(message));
+                messages.truncate(index);
+                check_result = Err(e);
+            }
+        }
+        self.dispatch_batch(messages).await.and(check_result)
+    }
+
+    /// Gets the features supported by this transport.
+    ///
+    /// Messages are delivered in the order in which they have been sent if the transport uses
+    /// [`DispatchMode::Sequential`]. Otherwise, messages are delivered in the order of their priority.
+    fn capabilities(&self) -> TransportCapabilities {
+        let ordering = match self.dispatch_mode {
+            DispatchMode::Sequential => MessageOrdering::PerSender,
+            DispatchMode::Queued { .. } => MessageOrdering::Unordered,
+        };
+        TransportCapabilities::default()
+            .with_push_delivery(true)
+            .with_pull_delivery(true)
+            .with_message_types(&[
+                UMessageType::UMESSAGE_TYPE_PUBLISH,
+                UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                UMessageType::UMESSAGE_TYPE_REQUEST,
+                UMessageType::UMESSAGE_TYPE_RESPONSE,
+            ])
+            .with_ordering(ordering)
+    }
+
+    fn statistics(&self) -> Option<TransportStatistics> {
+        Some(self.statistics.snapshot())
+    }
+
+    /// Gets the state of this transport's connection.
+    ///
+    /// A local transport is always connected.
+    fn connection_state(&self) -> ConnectionState {
+        ConnectionState::Connected
+    }
+
+    /// Accepts any listener without ever invoking it, because the state of a local transport never changes.
+    fn register_connection_state_listener(
+        &self,
+        _listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
         Ok(())
     }
 
+    fn unregister_connection_state_listener(
+        &self,
+        _listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        Ok(())
+    }
+
+    /// Fetches the next buffered message for a [registered pull filter](Self::register_pull_filter).
+    ///
+    /// Messages are fetched in the order of their priority, messages of the same priority are
+    /// fetched in the order in which they have been sent.
+    ///
+    /// This function does not wait for a message to arrive. Use [`LocalTransport::receive_timeout`]
+    /// for that purpose.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::FAILED_PRECONDITION`] if no pull filter has been registered for the given filters,
+    /// * [`UCode::NOT_FOUND`] if no message is currently buffered for the given filters.
+    async fn receive(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<UMessage, UStatus> {
+        let buffer = self.get_pull_buffer(source_filter, sink_filter).await?;
+        std::iter::from_fn(|| buffer.try_pop())
+            .find(|msg| is_deliverable(msg, self.strict, &self.statistics))
+            .ok_or_else(|| UStatus::fail_with_code(UCode::NOT_FOUND, "no message available"))
+    }
+
+    /// Registers a listener for messages matching the given filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the transport is in [strict mode](LocalTransport::with_strict_mode)
+    ///   and the filters do not pass [`verify_filter_criteria`],
+    /// * [`UCode::ALREADY_EXISTS`] if the listener has already been registered for the given filters.
     async fn register_listener(
         &self,
         source_filter: &UUri,
         sink_filter: Option<&UUri>,
         listener: Arc<dyn UListener>,
     ) -> Result<(), UStatus> {
-        let registered_listener = RegisteredListener {
-            source_filter: source_filter.to_owned(),
-            sink_filter: sink_filter.map(|u| u.to_owned()),
-            listener: ComparableListener::new(listener),
-        };
+        if self.strict {
+            verify_filter_criteria(source_filter, sink_filter)?;
+        }
+        let mut registered_listener = RegisteredListener::new(listener);
         let mut listeners = self.listeners.write().await;
-        if listeners.contains(&registered_listener) {
+        if listeners.contains(source_filter, sink_filter, &registered_listener) {
             Err(UStatus::fail_with_code(
-                crate::UCode::ALREADY_EXISTS,
+                UCode::ALREADY_EXISTS,
                 "listener already registered for filters",
             ))
         } else {
-            listeners.insert(registered_listener);
+            registered_listener.queue = self.start_worker(&registered_listener.listener);
+            listeners.insert(source_filter, sink_filter, registered_listener);
+            self.statistics
+                .record_listener_registered(source_filter, sink_filter);
             Ok(())
         }
     }
@@ -118,17 +791,20 @@ impl UTransport for LocalTransport {
         sink_filter: Option<&UUri>,
         listener: Arc<dyn UListener>,
     ) -> Result<(), UStatus> {
-        let registered_listener = RegisteredListener {
-            source_filter: source_filter.to_owned(),
-            sink_filter: sink_filter.map(|u| u.to_owned()),
-            listener: ComparableListener::new(listener),
-        };
+        let registered_listener = RegisteredListener::new(listener);
         let mut listeners = self.listeners.write().await;
-        if listeners.remove(&registered_listener) {
+        if let Some(removed_listener) =
+            listeners.remove(source_filter, sink_filter, &registered_listener)
+        {
+            if let Some(queue) = removed_listener.queue {
+                queue.close();
+            }
+            self.statistics
+                .record_listener_unregistered(source_filter, sink_filter);
             Ok(())
         } else {
             Err(UStatus::fail_with_code(
-                crate::UCode::NOT_FOUND,
+                UCode::NOT_FOUND,
                 "no such listener registered for filters",
             ))
         }
@@ -138,7 +814,53 @@ impl UTransport for LocalTransport {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{utransport::MockUListener, LocalUriProvider, StaticUriProvider, UMessageBuilder};
+    use crate::{
+        utransport::MockUListener, LocalUriProvider, StaticUriProvider, UMessageBuilder, UPriority,
+        UUID,
+    };
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    use test_case::test_case;
+    use tokio::sync::{mpsc, Semaphore};
+
+    /// A listener that forwards the IDs of received messages to a channel and then
+    /// waits for a permit before returning.
+    struct BlockingListener {
+        received: mpsc::UnboundedSender<UUID>,
+        permits: Arc<Semaphore>,
+    }
+
+    #[async_trait::async_trait]
+    impl UListener for BlockingListener {
+        async fn on_receive(&self, msg: UMessage) {
+            let _ = self.received.send(msg.id_unchecked().to_owned());
+            if let Ok(permit) = self.permits.acquire().await {
+                permit.forget();
+            }
+        }
+    }
+
+    struct RegisteringListener {
+        transport: Arc<LocalTransport>,
+        source_filter: UUri,
+    }
+
+    #[async_trait::async_trait]
+    impl UListener for RegisteringListener {
+        async fn on_receive(&self, _msg: UMessage) {
+            let mut other_listener = MockUListener::new();
+            other_listener.expect_on_receive().never();
+            self.transport
+                .register_listener(&self.source_filter, None, Arc::new(other_listener))
+                .await
+                .expect("failed to register listener");
+        }
+    }
+
+    fn new_publish_message(uri_provider: &StaticUriProvider, resource_id: u16) -> UMessage {
+        UMessageBuilder::publish(uri_provider.get_resource_uri(resource_id))
+            .build()
+            .unwrap()
+    }
 
     #[tokio::test]
     async fn test_send_dispatches_to_matching_listener() {
@@ -223,4 +945,586 @@ mod tests {
             )
             .await;
     }
+
+    #[tokio::test]
+    async fn test_listener_can_register_listener_while_processing_message() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = Arc::new(LocalTransport::default());
+        let listener = Arc::new(RegisteringListener {
+            transport: transport.clone(),
+            source_filter: uri_provider.get_resource_uri(RESOURCE_ID + 1),
+        });
+        transport
+            .register_listener(&uri_provider.get_resource_uri(RESOURCE_ID), None, listener)
+            .await
+            .unwrap();
+
+        let send_result = tokio::time::timeout(
+            Duration::from_secs(2),
+            transport.send(new_publish_message(&uri_provider, RESOURCE_ID)),
+        )
+        .await;
+        assert!(send_result.is_ok_and(|result| result.is_ok()));
+    }
+
+    #[tokio::test]
+    async fn test_queued_dispatch_does_not_wait_for_slow_listener() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default().with_dispatch_mode(DispatchMode::Queued {
+            queue_depth: 10,
+            overflow_policy: OverflowPolicy::Fail,
+        });
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let slow_listener = Arc::new(BlockingListener {
+            received: tx,
+            permits: permits.clone(),
+        });
+        let mut other_listener = MockUListener::new();
+        other_listener.expect_on_receive().times(3).return_const(());
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        transport
+            .register_listener(&topic, None, slow_listener)
+            .await
+            .unwrap();
+        transport
+            .register_listener(&topic, None, Arc::new(other_listener))
+            .await
+            .unwrap();
+
+        let mut sent_ids = vec![];
+        for _i in 0..3 {
+            let msg = new_publish_message(&uri_provider, RESOURCE_ID);
+            sent_ids.push(msg.id_unchecked().to_owned());
+            let send_result =
+                tokio::time::timeout(Duration::from_millis(500), transport.send(msg)).await;
+            assert!(send_result.is_ok_and(|result| result.is_ok()));
+        }
+
+        permits.add_permits(3);
+        for expected_id in sent_ids {
+            assert_eq!(rx.recv().await, Some(expected_id));
+        }
+    }
+
+    #[test_case(OverflowPolicy::DropOldest; "drop oldest")]
+    #[test_case(OverflowPolicy::DropNewest; "drop newest")]
+    #[test_case(OverflowPolicy::Fail; "fail")]
+    #[tokio::test]
+    async fn test_queued_dispatch_applies_overflow_policy(overflow_policy: OverflowPolicy) {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        // GIVEN a transport using queues of depth 1
+        let transport = LocalTransport::default().with_dispatch_mode(DispatchMode::Queued {
+            queue_depth: 1,
+            overflow_policy,
+        });
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let listener = Arc::new(BlockingListener {
+            received: tx,
+            permits: permits.clone(),
+        });
+        transport
+            .register_listener(&uri_provider.get_resource_uri(RESOURCE_ID), None, listener)
+            .await
+            .unwrap();
+
+        // and a listener that is busy processing a first message
+        let first_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let first_id = first_msg.id_unchecked().to_owned();
+        assert!(transport.send(first_msg).await.is_ok());
+        assert_eq!(rx.recv().await, Some(first_id));
+
+        // WHEN sending two more messages
+        let second_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let second_id = second_msg.id_unchecked().to_owned();
+        let third_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let third_id = third_msg.id_unchecked().to_owned();
+        assert!(transport.send(second_msg).await.is_ok());
+        let overflow_result = transport.send(third_msg).await;
+
+        // THEN the overflow policy determines the outcome
+        permits.add_permits(3);
+        match overflow_policy {
+            OverflowPolicy::DropOldest => {
+                assert!(overflow_result.is_ok());
+                assert_eq!(rx.recv().await, Some(third_id));
+            }
+            OverflowPolicy::DropNewest => {
+                assert!(overflow_result.is_ok());
+                assert_eq!(rx.recv().await, Some(second_id));
+            }
+            OverflowPolicy::Fail => {
+                assert!(overflow_result.is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+                assert_eq!(rx.recv().await, Some(second_id));
+            }
+        }
+        // and no other message is being delivered
+        assert!(tokio::time::timeout(Duration::from_millis(100), rx.recv())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_queued_dispatch_delivers_messages_in_priority_order() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        // GIVEN a listener that is busy processing a first message
+        let transport = LocalTransport::default().with_dispatch_mode(DispatchMode::Queued {
+            queue_depth: 10,
+            overflow_policy: OverflowPolicy::Fail,
+        });
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let listener = Arc::new(BlockingListener {
+            received: tx,
+            permits: permits.clone(),
+        });
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        transport
+            .register_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        let first_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let first_id = first_msg.id_unchecked().to_owned();
+        transport.send(first_msg).await.unwrap();
+        assert_eq!(rx.recv().await, Some(first_id));
+
+        // WHEN sending low priority messages followed by a high priority message
+        let mut expected_ids = vec![];
+        for priority in [
+            UPriority::UPRIORITY_CS1,
+            UPriority::UPRIORITY_CS1,
+            UPriority::UPRIORITY_CS6,
+        ] {
+            let msg = UMessageBuilder::publish(topic.clone())
+                .with_priority(priority)
+                .build()
+                .unwrap();
+            expected_ids.push(msg.id_unchecked().to_owned());
+            transport.send(msg).await.unwrap();
+        }
+        expected_ids.rotate_right(1);
+
+        // THEN the high priority message is delivered first
+        permits.add_permits(4);
+        for expected_id in expected_ids {
+            assert_eq!(rx.recv().await, Some(expected_id));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unregister_listener_discards_queued_messages() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default().with_dispatch_mode(DispatchMode::Queued {
+            queue_depth: 10,
+            overflow_policy: OverflowPolicy::DropOldest,
+        });
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let listener = Arc::new(BlockingListener {
+            received: tx,
+            permits: permits.clone(),
+        });
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+        let first_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let first_id = first_msg.id_unchecked().to_owned();
+        assert!(transport.send(first_msg).await.is_ok());
+        assert_eq!(rx.recv().await, Some(first_id));
+        assert!(transport
+            .send(new_publish_message(&uri_provider, RESOURCE_ID))
+            .await
+            .is_ok());
+
+        transport
+            .unregister_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        permits.add_permits(3);
+
+        // the worker task has ended and dropped its sender
+        assert!(tokio::time::timeout(Duration::from_millis(500), rx.recv())
+            .await
+            .is_ok_and(|received| received.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_receive_fails_for_unknown_pull_filter() {
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default();
+        let topic = uri_provider.get_resource_uri(0xa1b3);
+
+        assert!(transport
+            .receive(&topic, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::FAILED_PRECONDITION));
+        assert!(transport
+            .receive_timeout(&topic, None, Duration::from_millis(10))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::FAILED_PRECONDITION));
+        assert!(transport
+            .unregister_pull_filter(&topic, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_receive_returns_buffered_messages_in_order() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        // GIVEN a transport with a pull filter buffering up to two messages
+        let transport = LocalTransport::default();
+        let topic_filter = UUri::try_from_parts("my-vehicle", 0x100d, 0x02, 0xFFFF).unwrap();
+        transport
+            .register_pull_filter(&topic_filter, None, 2)
+            .await
+            .unwrap();
+        assert!(transport
+            .register_pull_filter(&topic_filter, None, 2)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+
+        // WHEN sending three matching messages and a non-matching one
+        let messages: Vec<UMessage> = (0..3)
+            .map(|_i| new_publish_message(&uri_provider, RESOURCE_ID))
+            .collect();
+        for msg in messages.iter() {
+            transport.send(msg.to_owned()).await.unwrap();
+        }
+        let other_provider = StaticUriProvider::new("my-vehicle", 0x200d, 0x02);
+        transport
+            .send(new_publish_message(&other_provider, RESOURCE_ID))
+            .await
+            .unwrap();
+
+        // THEN the two most recent matching messages can be fetched
+        assert_eq!(
+            transport.receive(&topic_filter, None).await.unwrap(),
+            messages[1]
+        );
+        assert_eq!(
+            transport.receive(&topic_filter, None).await.unwrap(),
+            messages[2]
+        );
+        assert!(transport
+            .receive(&topic_filter, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_receive_timeout_waits_for_message() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = Arc::new(LocalTransport::default());
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        transport
+            .register_pull_filter(&topic, None, 10)
+            .await
+            .unwrap();
+
+        // a message that is sent while waiting is being received
+        let msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        let sending_transport = transport.clone();
+        let msg_to_send = msg.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            sending_transport.send(msg_to_send).await
+        });
+        assert_eq!(
+            transport
+                .receive_timeout(&topic, None, Duration::from_secs(2))
+                .await
+                .unwrap(),
+            msg
+        );
+
+        // and waiting in vain results in a timeout
+        assert!(transport
+            .receive_timeout(&topic, None, Duration::from_millis(20))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::DEADLINE_EXCEEDED));
+
+        // and unregistering discards all buffered messages
+        transport.send(msg).await.unwrap();
+        transport
+            .unregister_pull_filter(&topic, None)
+            .await
+            .unwrap();
+        assert!(transport
+            .receive(&topic, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::FAILED_PRECONDITION));
+    }
+
+    #[tokio::test]
+    async fn test_unregister_pull_filter_cancels_all_waiting_receivers() {
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = Arc::new(LocalTransport::default());
+        let topic = uri_provider.get_resource_uri(0xa1b3);
+        transport
+            .register_pull_filter(&topic, None, 10)
+            .await
+            .unwrap();
+
+        // GIVEN multiple receivers waiting for a message
+        let receivers: Vec<_> = (0..3)
+            .map(|_| {
+                let transport = transport.clone();
+                let topic = topic.clone();
+                tokio::spawn(async move {
+                    transport
+                        .receive_timeout(&topic, None, Duration::from_secs(5))
+                        .await
+                })
+            })
+            .collect();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // WHEN the pull filter is being unregistered
+        transport
+            .unregister_pull_filter(&topic, None)
+            .await
+            .unwrap();
+
+        // THEN all receivers are cancelled right away
+        for receiver in receivers {
+            let result = tokio::time::timeout(Duration::from_secs(1), receiver)
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(result.is_err_and(|e| e.get_code() == UCode::CANCELLED));
+        }
+    }
+
+    fn new_expired_message(uri_provider: &StaticUriProvider, resource_id: u16) -> UMessage {
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("current system time is set to a point in time before UNIX Epoch")
+            - Duration::from_millis(1000);
+        UMessageBuilder::publish(uri_provider.get_resource_uri(resource_id))
+            .with_message_id(UUID::build_for_timestamp(created))
+            .with_ttl(500)
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_send_batch_dispatches_messages_in_order() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default();
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let listener = BlockingListener {
+            received: tx,
+            permits: Arc::new(Semaphore::new(Semaphore::MAX_PERMITS)),
+        };
+        transport
+            .register_listener(&topic, None, Arc::new(listener))
+            .await
+            .unwrap();
+        transport
+            .register_pull_filter(&topic, None, 10)
+            .await
+            .unwrap();
+
+        let messages: Vec<UMessage> = (0..3)
+            .map(|_| new_publish_message(&uri_provider, RESOURCE_ID))
+            .collect();
+        assert!(transport.send_batch(messages.clone()).await.is_ok());
+
+        for message in messages {
+            assert_eq!(rx.recv().await.as_ref(), message.id());
+            assert_eq!(transport.receive(&topic, None).await.unwrap(), message);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_batch_stops_at_invalid_message_in_strict_mode() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default().with_strict_mode(true);
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().once().return_const(());
+        transport
+            .register_listener(
+                &uri_provider.get_resource_uri(RESOURCE_ID),
+                None,
+                Arc::new(listener),
+            )
+            .await
+            .unwrap();
+
+        let mut invalid_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        invalid_msg.attributes.as_mut().unwrap().id.clear();
+        let messages = vec![
+            new_publish_message(&uri_provider, RESOURCE_ID),
+            invalid_msg,
+            new_publish_message(&uri_provider, RESOURCE_ID),
+        ];
+        assert!(transport
+            .send_batch(messages)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+
+    #[test_case(true; "in strict mode")]
+    #[test_case(false; "in lenient mode")]
+    #[tokio::test]
+    async fn test_send_checks_attributes_in_strict_mode_only(strict: bool) {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default().with_strict_mode(strict);
+        let mut listener = MockUListener::new();
+        listener
+            .expect_on_receive()
+            .times(if strict { 0 } else { 2 })
+            .return_const(());
+        transport
+            .register_listener(
+                &uri_provider.get_resource_uri(RESOURCE_ID),
+                None,
+                Arc::new(listener),
+            )
+            .await
+            .unwrap();
+
+        let mut invalid_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        invalid_msg.attributes.as_mut().unwrap().id.clear();
+        let result = transport.send(invalid_msg).await;
+        assert_eq!(
+            result.is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT),
+            strict
+        );
+
+        let expired_msg = new_expired_message(&uri_provider, RESOURCE_ID);
+        let result = transport.send(expired_msg).await;
+        assert_eq!(
+            result.is_err_and(|e| e.get_code() == UCode::DEADLINE_EXCEEDED),
+            strict
+        );
+    }
+
+    #[test_case(true; "in strict mode")]
+    #[test_case(false; "in lenient mode")]
+    #[tokio::test]
+    async fn test_registration_checks_filters_in_strict_mode_only(strict: bool) {
+        let transport = LocalTransport::default().with_strict_mode(strict);
+        // a source filter with a method resource ID cannot match any message without a sink
+        let invalid_source_filter =
+            UUri::try_from_parts("my-vehicle", 0x100d, 0x02, 0x0001).unwrap();
+
+        let result = transport
+            .register_listener(&invalid_source_filter, None, Arc::new(MockUListener::new()))
+            .await;
+        assert_eq!(
+            result.is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT),
+            strict
+        );
+        let result = transport
+            .register_pull_filter(&invalid_source_filter, None, 10)
+            .await;
+        assert_eq!(
+            result.is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT),
+            strict
+        );
+    }
+
+    #[tokio::test]
+    async fn test_statistics_reflect_dispatched_messages_and_listeners() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        let transport = LocalTransport::default().with_strict_mode(true);
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().once().return_const(());
+        let listener = Arc::new(listener);
+        transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+
+        assert!(transport
+            .send(new_publish_message(&uri_provider, RESOURCE_ID))
+            .await
+            .is_ok());
+        assert!(transport
+            .send(new_expired_message(&uri_provider, RESOURCE_ID))
+            .await
+            .is_err());
+
+        let statistics = transport.statistics().unwrap();
+        let counters = statistics.messages(
+            UMessageType::UMESSAGE_TYPE_PUBLISH,
+            UPriority::UPRIORITY_CS1,
+        );
+        assert_eq!(counters.sent, 1);
+        assert_eq!(counters.delivered, 1);
+        assert_eq!(counters.dropped, 1);
+        assert_eq!(statistics.listener_count(&topic, None), 1);
+        assert_eq!(statistics.delivery_latency().count, 1);
+
+        transport
+            .unregister_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        assert_eq!(transport.statistics().unwrap().total_listener_count(), 0);
+    }
+
+    #[test_case(DispatchMode::Sequential; "with sequential dispatch")]
+    #[test_case(DispatchMode::Queued { queue_depth: 10, overflow_policy: OverflowPolicy::DropOldest }; "with queued dispatch")]
+    #[tokio::test]
+    async fn test_strict_transport_passes_conformance_suite(dispatch_mode: DispatchMode) {
+        crate::conformance::ConformanceSuite::new(|| async move {
+            Arc::new(
+                LocalTransport::default()
+                    .with_dispatch_mode(dispatch_mode)
+                    .with_strict_mode(true),
+            ) as Arc<dyn UTransport>
+        })
+        .assert_conformance()
+        .await;
+    }
+
+    #[tokio::test]
+    async fn test_strict_mode_discards_messages_expiring_before_delivery() {
+        const RESOURCE_ID: u16 = 0xa1b3;
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+        // GIVEN a strict transport with a pull filter
+        let transport = LocalTransport::default().with_strict_mode(true);
+        let topic = uri_provider.get_resource_uri(RESOURCE_ID);
+        transport
+            .register_pull_filter(&topic, None, 10)
+            .await
+            .unwrap();
+
+        // WHEN sending a message with a short TTL that is not fetched in time
+        let short_lived_msg = UMessageBuilder::publish(topic.clone())
+            .with_ttl(50)
+            .build()
+            .unwrap();
+        let long_lived_msg = new_publish_message(&uri_provider, RESOURCE_ID);
+        transport.send(short_lived_msg).await.unwrap();
+        transport.send(long_lived_msg.clone()).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // THEN the expired message is discarded
+        assert_eq!(
+            transport.receive(&topic, None).await.unwrap(),
+            long_lived_msg
+        );
+        assert!(transport
+            .receive(&topic, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
 }
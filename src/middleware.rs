@@ -0,0 +1,750 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides means for adding cross-cutting behavior to an existing [`UTransport`].
+
+An [`Interceptor`] gets invoked whenever a message is being sent, a message is being delivered to
+a listener and whenever a listener is being (un)registered. An [`InterceptedTransport`] wraps an
+existing transport and invokes an interceptor before delegating to the wrapped transport.
+
+Multiple interceptors can be stacked on top of each other using a [`TransportBuilder`]:
+
+```rust
+use std::sync::Arc;
+use up_rust::{
+    local_transport::LocalTransport,
+    middleware::{LoggingInterceptor, StatisticsInterceptor, TransportBuilder, ValidatingInterceptor},
+};
+
+let transport = TransportBuilder::new(Arc::new(LocalTransport::default()))
+    .layer(Arc::new(ValidatingInterceptor))
+    .layer(Arc::new(StatisticsInterceptor::default()))
+    .layer(Arc::new(LoggingInterceptor))
+    .build();
+```
+*/
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use async_trait::async_trait;
+use tracing::debug;
+
+use crate::{
+    local_transport::check_message, verify_filter_criteria, ComparableListener, ConnectionState,
+    ConnectionStateListener, StatisticsRecorder, TransportCapabilities, TransportStatistics, UCode,
+    UListener, UMessage, UStatus, UTransport, UUri,
+};
+
+/// A component that adds behavior to a [`UTransport`].
+///
+/// All hooks have default implementations that do nothing, so implementations only need to
+/// override the hooks that they are interested in.
+#[async_trait]
+pub trait Interceptor: Send + Sync {
+    /// Invoked before a message is being sent.
+    ///
+    /// # Returns
+    ///
+    /// The (possibly altered) message to send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the message should not be sent. The error is returned to the sender.
+    async fn on_send(&self, message: UMessage) -> Result<UMessage, UStatus> {
+        Ok(message)
+    }
+
+    /// Invoked after a message has been passed to the wrapped transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - The message that has been passed to the wrapped transport.
+    /// * `result` - The outcome of sending the message.
+    async fn on_send_result(&self, _message: &UMessage, _result: &Result<(), UStatus>) {}
+
+    /// Invoked before a message is being delivered to a listener or returned from [`UTransport::receive`].
+    ///
+    /// # Returns
+    ///
+    /// The (possibly altered) message to deliver or `None` if the message should be discarded.
+    async fn on_receive(&self, message: UMessage) -> Option<UMessage> {
+        Some(message)
+    }
+
+    /// Invoked before a listener is being registered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener should not be registered. The error is returned to the caller.
+    async fn on_register_listener(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        Ok(())
+    }
+
+    /// Invoked after a listener has been passed to the wrapped transport for registration.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The outcome of registering the listener.
+    async fn on_register_listener_result(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+        _result: &Result<(), UStatus>,
+    ) {
+    }
+
+    /// Invoked before a listener is being unregistered.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener should not be unregistered. The error is returned to the caller.
+    async fn on_unregister_listener(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        Ok(())
+    }
+
+    /// Invoked after a listener has been passed to the wrapped transport for unregistration.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The outcome of unregistering the listener.
+    async fn on_unregister_listener_result(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+        _result: &Result<(), UStatus>,
+    ) {
+    }
+
+    /// Gets statistics that this interceptor has collected.
+    ///
+    /// An [`InterceptedTransport`] returns these statistics instead of the wrapped transport's statistics.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the interceptor does not collect any statistics.
+    fn statistics(&self) -> Option<TransportStatistics> {
+        None
+    }
+}
+
+/// Wraps a transport with additional behavior.
+pub trait Layer {
+    /// Wraps a transport.
+    ///
+    /// # Returns
+    ///
+    /// A transport that delegates to the given transport.
+    fn layer(&self, inner: Arc<dyn UTransport>) -> Arc<dyn UTransport>;
+}
+
+impl<I: Interceptor + 'static> Layer for Arc<I> {
+    fn layer(&self, inner: Arc<dyn UTransport>) -> Arc<dyn UTransport> {
+        Arc::new(InterceptedTransport::new(inner, self.clone()))
+    }
+}
+
+/// A builder for stacking [`Layer`]s on top of a transport.
+///
+/// Layers are applied in the order in which they are being added, i.e. the layer added last
+/// is the outermost one. It is invoked first when sending a message and last when a message is
+/// being delivered to a listener.
+pub struct TransportBuilder {
+    transport: Arc<dyn UTransport>,
+}
+
+impl TransportBuilder {
+    /// Creates a new builder for a transport.
+    pub fn new(transport: Arc<dyn UTransport>) -> Self {
+        TransportBuilder { transport }
+    }
+
+    /// Wraps the transport built so far with a layer.
+    pub fn layer<L: Layer>(self, layer: L) -> Self {
+        TransportBuilder {
+            transport: layer.layer(self.transport),
+        }
+    }
+
+    /// Gets the transport including all layers.
+    pub fn build(self) -> Arc<dyn UTransport> {
+        self.transport
+    }
+}
+
+/// A listener that lets an interceptor process messages before they are passed on to the actual listener.
+struct InterceptingListener {
+    listener: Arc<dyn UListener>,
+    interceptor: Arc<dyn Interceptor>,
+}
+
+#[async_trait]
+impl UListener for InterceptingListener {
+    async fn on_receive(&self, msg: UMessage) {
+        if let Some(msg) = self.interceptor.on_receive(msg).await {
+            self.listener.on_receive(msg).await;
+        }
+    }
+}
+
+/// The filters and listener that a listener has been registered for by a client.
+type Registration = (UUri, Option<UUri>, ComparableListener);
+type RegisteredListeners = HashMap<Registration, Arc<dyn UListener>>;
+
+/// A [`UTransport`] that invokes an [`Interceptor`] before delegating to another transport.
+pub struct InterceptedTransport {
+    inner: Arc<dyn UTransport>,
+    interceptor: Arc<dyn Interceptor>,
+    // the listeners that have been registered with the inner transport on behalf of the clients' listeners
+    listeners: Mutex<RegisteredListeners>,
+}
+
+impl InterceptedTransport {
+    /// Creates a new transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The transport to delegate to.
+    /// * `interceptor` - The interceptor to invoke.
+    pub fn new(inner: Arc<dyn UTransport>, interceptor: Arc<dyn Interceptor>) -> Self {
+        InterceptedTransport {
+            inner,
+            interceptor,
+            listeners: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock_listeners(&self) -> Result<MutexGuard<'_, RegisteredListeners>, UStatus> {
+        self.listeners.lock().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })
+    }
+}
+
+#[async_trait]
+impl UTransport for InterceptedTransport {
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        let message = self.interceptor.on_send(message).await?;
+        let result = self.inner.send(message.clone()).await;
+        self.interceptor.on_send_result(&message, &result).await;
+        result
+    }
+
+    /// Invokes the interceptor for each message and sends the resulting messages using the
+    /// inner transport's [`UTransport::send_batch`].
+    ///
+    /// If the interceptor rejects a message, the preceding messages are sent and the interceptor's
+    /// error is returned. The outcome of sending the batch is reported to the interceptor for each
+    /// of the messages that have been passed to the inner transport.
+    async fn send_batch(&self, messages: Vec<UMessage>) -> Result<(), UStatus> {
+        let mut intercepted_messages = Vec::with_capacity(messages.len());
+        let mut interceptor_result = Ok(());
+        for message in messages {
+            match self.interceptor.on_send(message).await {
+                Ok(message) => intercepted_messages.push(message),
+                Err(e) => {
+                    interceptor_result = Err(e);
+                    break;
+                }
+            }
+        }
+        if intercepted_messages.is_empty() {
+            return interceptor_result;
+        }
+        let result = self.inner.send_batch(intercepted_messages.clone()).await;
+        for message in &intercepted_messages {
+            self.interceptor.on_send_result(message, &result).await;
+        }
+        result.and(interceptor_result)
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        self.inner.capabilities()
+    }
+
+    fn statistics(&self) -> Option<TransportStatistics> {
+        self.interceptor
+            .statistics()
+            .or_else(|| self.inner.statistics())
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        self.inner.connection_state()
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.inner.register_connection_state_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.inner.unregister_connection_state_listener(listener)
+    }
+
+    /// Receives a message from the wrapped transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::NOT_FOUND`] if the interceptor has discarded the received message.
+    async fn receive(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<UMessage, UStatus> {
+        let message = self.inner.receive(source_filter, sink_filter).await?;
+        self.interceptor
+            .on_receive(message)
+            .await
+            .ok_or_else(|| UStatus::fail_with_code(UCode::NOT_FOUND, "message has been discarded"))
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.interceptor
+            .on_register_listener(source_filter, sink_filter)
+            .await?;
+        let registration = (
+            source_filter.to_owned(),
+            sink_filter.cloned(),
+            ComparableListener::new(listener.clone()),
+        );
+        let (intercepting_listener, is_new) = {
+            let mut listeners = self.lock_listeners()?;
+            match listeners.get(&registration) {
+                // let the inner transport decide how to handle duplicate registrations
+                Some(existing) => (existing.clone(), false),
+                None => {
+                    let intercepting_listener: Arc<dyn UListener> =
+                        Arc::new(InterceptingListener {
+                            listener,
+                            interceptor: self.interceptor.clone(),
+                        });
+                    listeners.insert(registration.clone(), intercepting_listener.clone());
+                    (intercepting_listener, true)
+                }
+            }
+        };
+        let result = self
+            .inner
+            .register_listener(source_filter, sink_filter, intercepting_listener)
+            .await;
+        if result.is_err() && is_new {
+            self.lock_listeners()?.remove(&registration);
+        }
+        self.interceptor
+            .on_register_listener_result(source_filter, sink_filter, &result)
+            .await;
+        result
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.interceptor
+            .on_unregister_listener(source_filter, sink_filter)
+            .await?;
+        let registration = (
+            source_filter.to_owned(),
+            sink_filter.cloned(),
+            ComparableListener::new(listener),
+        );
+        let Some(intercepting_listener) = self.lock_listeners()?.get(&registration).cloned() else {
+            return Err(UStatus::fail_with_code(
+                UCode::NOT_FOUND,
+                "no such listener registered for filters",
+            ));
+        };
+        let result = self
+            .inner
+            .unregister_listener(source_filter, sink_filter, intercepting_listener)
+            .await;
+        if result.is_ok() {
+            self.lock_listeners()?.remove(&registration);
+        }
+        self.interceptor
+            .on_unregister_listener_result(source_filter, sink_filter, &result)
+            .await;
+        result
+    }
+}
+
+/// An [`Interceptor`] that logs all messages and (un)registrations of listeners using `tracing`.
+///
+/// All events are logged at `DEBUG` level, failures to send a message are logged at `INFO` level.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LoggingInterceptor;
+
+#[async_trait]
+impl Interceptor for LoggingInterceptor {
+    async fn on_send(&self, message: UMessage) -> Result<UMessage, UStatus> {
+        debug!(
+            id = ?message.id().map(|id| id.to_hyphenated_string()),
+            r#type = ?message.type_(),
+            source = ?message.source().map(|uri| uri.to_uri(false)),
+            sink = ?message.sink().map(|uri| uri.to_uri(false)),
+            "sending message"
+        );
+        Ok(message)
+    }
+
+    async fn on_send_result(&self, message: &UMessage, result: &Result<(), UStatus>) {
+        if let Err(e) = result {
+            tracing::info!(
+                id = ?message.id().map(|id| id.to_hyphenated_string()),
+                code = ?e.get_code(),
+                "failed to send message: {}",
+                e.get_message()
+            );
+        }
+    }
+
+    async fn on_receive(&self, message: UMessage) -> Option<UMessage> {
+        debug!(
+            id = ?message.id().map(|id| id.to_hyphenated_string()),
+            r#type = ?message.type_(),
+            source = ?message.source().map(|uri| uri.to_uri(false)),
+            sink = ?message.sink().map(|uri| uri.to_uri(false)),
+            "delivering message"
+        );
+        Some(message)
+    }
+
+    async fn on_register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        debug!(
+            source_filter = source_filter.to_uri(false),
+            sink_filter = ?sink_filter.map(|uri| uri.to_uri(false)),
+            "registering listener"
+        );
+        Ok(())
+    }
+
+    async fn on_unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        debug!(
+            source_filter = source_filter.to_uri(false),
+            sink_filter = ?sink_filter.map(|uri| uri.to_uri(false)),
+            "unregistering listener"
+        );
+        Ok(())
+    }
+}
+
+/// An [`Interceptor`] that verifies messages and filters according to the uProtocol specification.
+///
+/// * Messages are only sent if their attributes are consistent with their type and if they have
+///   not expired yet. Otherwise, sending fails with [`UCode::INVALID_ARGUMENT`] or
+///   [`UCode::DEADLINE_EXCEEDED`] respectively.
+/// * Received messages that fail the same checks are discarded.
+/// * Listeners are only registered if the filters pass [`verify_filter_criteria`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ValidatingInterceptor;
+
+#[async_trait]
+impl Interceptor for ValidatingInterceptor {
+    async fn on_send(&self, message: UMessage) -> Result<UMessage, UStatus> {
+        check_message(&message)?;
+        Ok(message)
+    }
+
+    async fn on_receive(&self, message: UMessage) -> Option<UMessage> {
+        match check_message(&message) {
+            Ok(()) => Some(message),
+            Err(e) => {
+                debug!("discarding message [{}]", e.get_message());
+                None
+            }
+        }
+    }
+
+    async fn on_register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<(), UStatus> {
+        verify_filter_criteria(source_filter, sink_filter)
+    }
+}
+
+/// An [`Interceptor`] that collects [`TransportStatistics`] about the messages and listeners
+/// passing an [`InterceptedTransport`].
+///
+/// Messages that have been sent successfully are counted as _sent_, messages that the wrapped transport
+/// has failed to send are counted as _dropped_. Messages are counted as _delivered_ whenever they are
+/// passed to a listener or returned from [`UTransport::receive`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use up_rust::{
+///     local_transport::LocalTransport,
+///     middleware::{Interceptor, StatisticsInterceptor, TransportBuilder},
+///     UMessageBuilder, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let interceptor = Arc::new(StatisticsInterceptor::default());
+/// let transport = TransportBuilder::new(Arc::new(LocalTransport::default()))
+///     .layer(interceptor.clone())
+///     .build();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// transport.send(UMessageBuilder::publish(topic).build().unwrap()).await.unwrap();
+/// assert_eq!(interceptor.statistics().unwrap().total_messages().sent, 1);
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct StatisticsInterceptor {
+    recorder: StatisticsRecorder,
+}
+
+impl StatisticsInterceptor {
+    /// Resets the message counters and latencies collected so far.
+    ///
+    /// The numbers of registered listeners are kept.
+    pub fn reset(&self) {
+        self.recorder.reset();
+    }
+}
+
+#[async_trait]
+impl Interceptor for StatisticsInterceptor {
+    async fn on_send_result(&self, message: &UMessage, result: &Result<(), UStatus>) {
+        if result.is_ok() {
+            self.recorder.record_sent(message);
+        } else {
+            self.recorder.record_dropped(message);
+        }
+    }
+
+    async fn on_receive(&self, message: UMessage) -> Option<UMessage> {
+        self.recorder.record_delivered(&message);
+        Some(message)
+    }
+
+    async fn on_register_listener_result(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        result: &Result<(), UStatus>,
+    ) {
+        if result.is_ok() {
+            self.recorder
+                .record_listener_registered(source_filter, sink_filter);
+        }
+    }
+
+    async fn on_unregister_listener_result(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        result: &Result<(), UStatus>,
+    ) {
+        if result.is_ok() {
+            self.recorder
+                .record_listener_unregistered(source_filter, sink_filter);
+        }
+    }
+
+    fn statistics(&self) -> Option<TransportStatistics> {
+        Some(self.recorder.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        utransport::{BatchRecordingTransport, MockTransport, MockUListener},
+        MessageCounters, UMessageBuilder,
+    };
+
+    /// Creates a transport that captures the listeners registered with it.
+    fn capturing_transport(captured: Arc<Mutex<Vec<Arc<dyn UListener>>>>) -> MockTransport {
+        let mut transport = MockTransport::new();
+        transport.expect_do_register_listener().returning(
+            move |_source_filter, _sink_filter, listener| {
+                captured.lock().unwrap().push(listener);
+                Ok(())
+            },
+        );
+        transport
+    }
+
+    #[tokio::test]
+    async fn test_layers_are_invoked_on_send() {
+        let mut inner = MockTransport::new();
+        inner.expect_do_send().once().returning(|_msg| Ok(()));
+        let statistics = Arc::new(StatisticsInterceptor::default());
+        let transport = TransportBuilder::new(Arc::new(inner))
+            .layer(Arc::new(ValidatingInterceptor))
+            .layer(statistics.clone())
+            .build();
+
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic).build().unwrap();
+        assert!(transport.send(msg.clone()).await.is_ok());
+
+        // a message without attributes is rejected by the validating layer
+        let invalid_msg = UMessage {
+            payload: msg.payload,
+            ..Default::default()
+        };
+        assert!(transport
+            .send(invalid_msg)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+        assert_eq!(
+            statistics.statistics().unwrap().total_messages(),
+            MessageCounters {
+                sent: 1,
+                delivered: 0,
+                dropped: 1
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_batch_is_forwarded_to_inner_transport() {
+        let inner = Arc::new(BatchRecordingTransport::default());
+        let statistics = Arc::new(StatisticsInterceptor::default());
+        let transport = TransportBuilder::new(inner.clone())
+            .layer(statistics.clone())
+            .layer(Arc::new(ValidatingInterceptor))
+            .build();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic).build().unwrap();
+
+        // a batch of valid messages is sent as a whole
+        transport
+            .send_batch(vec![msg.clone(), msg.clone()])
+            .await
+            .unwrap();
+        assert_eq!(inner.batches().pop(), Some(vec![msg.clone(), msg.clone()]));
+        assert_eq!(statistics.statistics().unwrap().total_messages().sent, 2);
+
+        // and only the messages preceding a rejected message are sent
+        let invalid_msg = UMessage::default();
+        assert!(transport
+            .send_batch(vec![msg.clone(), invalid_msg, msg.clone()])
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+        assert_eq!(inner.batches().pop(), Some(vec![msg]));
+        assert_eq!(statistics.statistics().unwrap().total_messages().sent, 3);
+    }
+
+    #[tokio::test]
+    async fn test_interceptor_is_invoked_on_delivery() {
+        let captured = Arc::new(Mutex::new(vec![]));
+        let statistics = Arc::new(StatisticsInterceptor::default());
+        let transport = InterceptedTransport::new(
+            Arc::new(capturing_transport(captured.clone())),
+            statistics.clone(),
+        );
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().once().return_const(());
+
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        transport
+            .register_listener(&topic, None, Arc::new(listener))
+            .await
+            .unwrap();
+        let registered_listener = captured.lock().unwrap().pop().unwrap();
+        registered_listener
+            .on_receive(UMessageBuilder::publish(topic).build().unwrap())
+            .await;
+
+        assert_eq!(
+            statistics.statistics().unwrap().total_messages().delivered,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unregister_listener_uses_registered_listener() {
+        let captured = Arc::new(Mutex::new(vec![]));
+        let mut inner = capturing_transport(captured.clone());
+        let expected_listener = captured.clone();
+        inner
+            .expect_do_unregister_listener()
+            .once()
+            .withf(move |_source_filter, _sink_filter, listener| {
+                expected_listener
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .any(|registered| Arc::ptr_eq(registered, listener))
+            })
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        let transport = InterceptedTransport::new(Arc::new(inner), Arc::new(LoggingInterceptor));
+        let listener: Arc<dyn UListener> = Arc::new(MockUListener::new());
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+
+        transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+        transport
+            .unregister_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+        assert!(transport
+            .unregister_listener(&topic, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_validating_interceptor_rejects_invalid_filters() {
+        let mut inner = MockTransport::new();
+        inner.expect_do_register_listener().never();
+        let transport = InterceptedTransport::new(Arc::new(inner), Arc::new(ValidatingInterceptor));
+        // a topic filter must not have a resource ID from the RPC method range
+        let source_filter = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+        assert!(transport
+            .register_listener(&source_filter, None, Arc::new(MockUListener::new()))
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+}
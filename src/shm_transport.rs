@@ -0,0 +1,962 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which can be used for exchanging large payloads between uEntities running
+in different processes on the same (Linux) host by means of POSIX shared memory.
+
+All [`ShmTransport`]s that open the same named segment exchange messages via a ring buffer
+consisting of a fixed number of fixed size _slots_. Each slot holds a single message, consisting
+of the protobuf encoding of the message's attributes followed by the raw payload bytes.
+
+Received payloads are not copied out of shared memory. Instead, the `Bytes` contained in a
+received message reference the slot that the message has been written to. The slot cannot be
+overwritten as long as any such reference exists. Receivers should therefore drop received
+payloads as soon as possible, otherwise senders will fail to send messages once the ring buffer
+has wrapped around to the slot.
+
+Receivers are notified about new messages by means of a futex in the shared memory segment.
+
+Slots are locked by means of counters in the shared memory segment, which are not released if a
+process terminates while holding a received payload or while writing to a slot. Such a slot remains
+locked until the segment is being [removed](ShmTransport::remove) and opened again by all transports,
+i.e. every time the ring buffer wraps around to the slot, sending a message fails.
+*/
+
+use std::{
+    ffi::CString,
+    io,
+    ptr::NonNull,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use protobuf::Message;
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, info, warn};
+
+use crate::{
+    utransport::FilterIndex, verify_filter_criteria, ComparableListener, MessageOrdering,
+    TransportCapabilities, UAttributes, UCode, UListener, UMessage, UMessageType, UStatus,
+    UTransport, UUri,
+};
+
+/// Identifies an initialized segment and the version of its layout.
+const MAGIC: u64 = 0x7550_524f_5348_4d01;
+/// The value of a slot's reader count while a sender is writing to the slot.
+const WRITER: u32 = u32::MAX;
+/// The size that the segment header and slot headers are padded to.
+const ALIGNMENT: usize = 64;
+/// The maximum time that a receiver waits for a notification before checking if it should stop.
+const WAIT_INTERVAL: Duration = Duration::from_millis(100);
+/// The maximum time to wait for another process to finish initializing a segment.
+const INIT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The layout of a shared memory segment used by a [`ShmTransport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ShmOptions {
+    /// The number of slots in the ring buffer, i.e. the maximum number of messages that can be
+    /// in flight at the same time.
+    pub slot_count: u32,
+    /// The maximum size of a message's encoded attributes and payload.
+    pub slot_size: u32,
+}
+
+impl Default for ShmOptions {
+    fn default() -> Self {
+        ShmOptions {
+            slot_count: 32,
+            slot_size: 1024 * 1024,
+        }
+    }
+}
+
+impl ShmOptions {
+    fn slot_stride(&self) -> usize {
+        (size_of::<SlotHeader>() + self.slot_size as usize).next_multiple_of(ALIGNMENT)
+    }
+
+    fn segment_len(&self) -> usize {
+        size_of::<SegmentHeader>().next_multiple_of(ALIGNMENT)
+            + self.slot_count as usize * self.slot_stride()
+    }
+}
+
+#[repr(C)]
+struct SegmentHeader {
+    magic: AtomicU64,
+    slot_count: u32,
+    slot_size: u32,
+    // the sequence number to use for the next message to be sent
+    head: AtomicU64,
+    // incremented whenever a message has been written, used as the futex word
+    published: AtomicU32,
+}
+
+#[repr(C)]
+struct SlotHeader {
+    // (sequence number + 1) << 1, with the lowest bit indicating that the message has been discarded
+    seq: AtomicU64,
+    // the number of references to the slot's content held by receivers, or WRITER
+    readers: AtomicU32,
+    attributes_len: AtomicU32,
+    payload_len: AtomicU32,
+}
+
+/// A shared memory segment that has been mapped into the process' address space.
+struct Segment {
+    ptr: NonNull<u8>,
+    len: usize,
+    options: ShmOptions,
+}
+
+// SAFETY: all shared state is accessed by means of atomics, slot contents are only
+// accessed while holding the slot as writer or reader
+unsafe impl Send for Segment {}
+unsafe impl Sync for Segment {}
+
+fn segment_name(name: &str) -> io::Result<CString> {
+    CString::new(format!("/{name}")).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+impl Segment {
+    /// Opens a named segment, creating and initializing it if it does not exist yet.
+    async fn open(name: &str, options: ShmOptions) -> io::Result<Self> {
+        if options.slot_count == 0 || options.slot_size == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "slot count and slot size must not be 0",
+            ));
+        }
+        let c_name = segment_name(name)?;
+        // SAFETY: c_name is a valid NUL terminated string
+        let fd = unsafe {
+            libc::shm_open(
+                c_name.as_ptr(),
+                libc::O_RDWR | libc::O_CREAT | libc::O_EXCL,
+                0o600,
+            )
+        };
+        if fd >= 0 {
+            let len = options.segment_len();
+            // SAFETY: fd is a valid file descriptor that we own
+            let result = unsafe {
+                if libc::ftruncate(fd, len as libc::off_t) == 0 {
+                    Self::map(fd, len, options)
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            };
+            // SAFETY: fd is a valid file descriptor that we own
+            unsafe { libc::close(fd) };
+            let segment = result?;
+            // SAFETY: other processes do not read the layout before the magic has been set
+            unsafe {
+                let header = segment.ptr.as_ptr() as *mut SegmentHeader;
+                (*header).slot_count = options.slot_count;
+                (*header).slot_size = options.slot_size;
+            }
+            segment.header().magic.store(MAGIC, Ordering::Release);
+            return Ok(segment);
+        }
+        let err = io::Error::last_os_error();
+        if err.kind() != io::ErrorKind::AlreadyExists {
+            return Err(err);
+        }
+        // SAFETY: c_name is a valid NUL terminated string
+        let fd = unsafe { libc::shm_open(c_name.as_ptr(), libc::O_RDWR, 0) };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let result = Self::attach(fd).await;
+        // SAFETY: fd is a valid file descriptor that we own
+        unsafe { libc::close(fd) };
+        result
+    }
+
+    /// Maps an existing segment that has been created by another transport.
+    ///
+    /// Waits for the other transport to finish initializing the segment, if necessary.
+    async fn attach(fd: libc::c_int) -> io::Result<Self> {
+        let header_len = size_of::<SegmentHeader>().next_multiple_of(ALIGNMENT);
+        let mut waited = Duration::ZERO;
+        loop {
+            // SAFETY: stat is a plain C struct for which all zeros is a valid value
+            let mut stat: libc::stat = unsafe { std::mem::zeroed() };
+            // SAFETY: fd is a valid file descriptor and stat points to a valid struct
+            if unsafe { libc::fstat(fd, &mut stat) } != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let len = stat.st_size as usize;
+            if len >= header_len {
+                // the layout is determined by the creator of the segment
+                // SAFETY: fd is a valid file descriptor for a segment of len bytes
+                let mut segment = unsafe { Self::map(fd, len, ShmOptions::default())? };
+                if segment.header().magic.load(Ordering::Acquire) == MAGIC {
+                    let header = segment.header();
+                    let options = ShmOptions {
+                        slot_count: header.slot_count,
+                        slot_size: header.slot_size,
+                    };
+                    if options.slot_count == 0 || options.segment_len() != len {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "shared memory segment has unexpected size",
+                        ));
+                    }
+                    segment.options = options;
+                    return Ok(segment);
+                }
+            }
+            if waited >= INIT_TIMEOUT {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    "shared memory segment has not been initialized",
+                ));
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            waited += Duration::from_millis(10);
+        }
+    }
+
+    /// Maps a segment into memory.
+    ///
+    /// # Safety
+    ///
+    /// `fd` must be a valid file descriptor for a shared memory object of at least `len` bytes.
+    unsafe fn map(fd: libc::c_int, len: usize, options: ShmOptions) -> io::Result<Self> {
+        let ptr = libc::mmap(
+            std::ptr::null_mut(),
+            len,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED,
+            fd,
+            0,
+        );
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(Segment {
+            // mmap never returns null for a successful mapping without MAP_FIXED
+            ptr: NonNull::new_unchecked(ptr as *mut u8),
+            len,
+            options,
+        })
+    }
+
+    fn header(&self) -> &SegmentHeader {
+        // SAFETY: the mapping starts with a properly aligned segment header
+        unsafe { &*(self.ptr.as_ptr() as *const SegmentHeader) }
+    }
+
+    fn slot_offset(&self, sequence_number: u64) -> usize {
+        let index = (sequence_number % self.options.slot_count as u64) as usize;
+        size_of::<SegmentHeader>().next_multiple_of(ALIGNMENT) + index * self.options.slot_stride()
+    }
+
+    fn slot(&self, sequence_number: u64) -> &SlotHeader {
+        // SAFETY: slot headers are located within the mapping at properly aligned offsets
+        unsafe { &*(self.ptr.as_ptr().add(self.slot_offset(sequence_number)) as *const SlotHeader) }
+    }
+
+    fn slot_data(&self, sequence_number: u64) -> *mut u8 {
+        // SAFETY: the slot's data area is located within the mapping
+        unsafe {
+            self.ptr
+                .as_ptr()
+                .add(self.slot_offset(sequence_number) + size_of::<SlotHeader>())
+        }
+    }
+
+    /// Writes a message to the next slot of the ring buffer and notifies all receivers.
+    fn write(&self, attributes: &[u8], payload: &[u8]) -> Result<(), UStatus> {
+        if attributes.len() + payload.len() > self.options.slot_size as usize {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "message exceeds slot size",
+            ));
+        }
+        let header = self.header();
+        let sequence_number = header.head.fetch_add(1, Ordering::AcqRel);
+        let seq = (sequence_number + 1) << 1;
+        let slot = self.slot(sequence_number);
+        if slot
+            .readers
+            .compare_exchange(0, WRITER, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            // let receivers skip this message
+            slot.seq.fetch_max(seq | 1, Ordering::Release);
+            self.notify();
+            return Err(UStatus::fail_with_code(
+                UCode::RESOURCE_EXHAUSTED,
+                "slot is still in use by receivers",
+            ));
+        }
+        if slot.seq.load(Ordering::Acquire) < seq {
+            slot.attributes_len
+                .store(attributes.len() as u32, Ordering::Relaxed);
+            slot.payload_len
+                .store(payload.len() as u32, Ordering::Relaxed);
+            // SAFETY: we hold the slot exclusively, the data area is large enough for the message
+            unsafe {
+                let data = self.slot_data(sequence_number);
+                std::ptr::copy_nonoverlapping(attributes.as_ptr(), data, attributes.len());
+                std::ptr::copy_nonoverlapping(
+                    payload.as_ptr(),
+                    data.add(attributes.len()),
+                    payload.len(),
+                );
+            }
+            slot.seq.fetch_max(seq, Ordering::Release);
+            slot.readers.store(0, Ordering::Release);
+            self.notify();
+            Ok(())
+        } else {
+            // the ring buffer has wrapped around while this sender was waiting for the slot
+            slot.readers.store(0, Ordering::Release);
+            Err(UStatus::fail_with_code(
+                UCode::RESOURCE_EXHAUSTED,
+                "slot has already been used for a newer message",
+            ))
+        }
+    }
+
+    fn notify(&self) {
+        let published = &self.header().published;
+        published.fetch_add(1, Ordering::Release);
+        // SAFETY: the futex word is a valid, aligned u32 within the shared mapping
+        unsafe {
+            libc::syscall(
+                libc::SYS_futex,
+                published.as_ptr(),
+                libc::FUTEX_WAKE,
+                i32::MAX,
+            );
+        }
+    }
+
+    /// Waits for a notification, unless `published` has changed already.
+    fn wait(&self, published: u32) {
+        let timeout = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: WAIT_INTERVAL.as_nanos() as libc::c_long,
+        };
+        // SAFETY: the futex word is a valid, aligned u32 within the shared mapping
+        unsafe {
+            libc::syscall(
+                libc::SYS_futex,
+                self.header().published.as_ptr(),
+                libc::FUTEX_WAIT,
+                published,
+                &timeout as *const libc::timespec,
+            );
+        }
+    }
+}
+
+impl Drop for Segment {
+    fn drop(&mut self) {
+        // SAFETY: ptr and len describe a mapping created by mmap
+        unsafe { libc::munmap(self.ptr.as_ptr() as *mut libc::c_void, self.len) };
+    }
+}
+
+/// A reference to (part of) the content of a slot, which prevents the slot from being overwritten.
+struct SlotLease {
+    segment: Arc<Segment>,
+    sequence_number: u64,
+    offset: usize,
+    len: usize,
+}
+
+impl AsRef<[u8]> for SlotLease {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: the slot cannot be written to while the lease exists, and the reader has
+        // verified that offset and len lie within the slot's data area
+        unsafe {
+            std::slice::from_raw_parts(
+                self.segment
+                    .slot_data(self.sequence_number)
+                    .add(self.offset),
+                self.len,
+            )
+        }
+    }
+}
+
+impl Drop for SlotLease {
+    fn drop(&mut self) {
+        self.segment
+            .slot(self.sequence_number)
+            .readers
+            .fetch_sub(1, Ordering::Release);
+    }
+}
+
+/// The outcome of trying to read the next message from the ring buffer.
+enum ReadResult {
+    Message(UMessage),
+    Skipped,
+    Empty,
+}
+
+/// Reads messages from the ring buffer in the order in which they have been written.
+struct Reader {
+    segment: Arc<Segment>,
+    next_sequence_number: u64,
+}
+
+impl Reader {
+    fn new(segment: Arc<Segment>) -> Self {
+        let next_sequence_number = segment.header().head.load(Ordering::Acquire);
+        Reader {
+            segment,
+            next_sequence_number,
+        }
+    }
+
+    fn try_read(&mut self) -> ReadResult {
+        let sequence_number = self.next_sequence_number;
+        let slot = self.segment.slot(sequence_number);
+        let seq = slot.seq.load(Ordering::Acquire);
+        let expected = (sequence_number + 1) << 1;
+        if seq & !1 < expected {
+            return ReadResult::Empty;
+        }
+        if seq & !1 > expected {
+            // the slot has been overwritten already, skip all messages that have been lost
+            let head = self.segment.header().head.load(Ordering::Acquire);
+            self.next_sequence_number = (sequence_number + 1)
+                .max(head.saturating_sub(self.segment.options.slot_count as u64));
+            debug!("receiver has been overrun, skipping messages");
+            return ReadResult::Skipped;
+        }
+        self.next_sequence_number += 1;
+        if seq & 1 == 1 {
+            return ReadResult::Skipped;
+        }
+        let mut readers = slot.readers.load(Ordering::Relaxed);
+        loop {
+            if readers == WRITER {
+                // a sender is overwriting the slot
+                return ReadResult::Skipped;
+            }
+            match slot.readers.compare_exchange_weak(
+                readers,
+                readers + 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(current) => readers = current,
+            }
+        }
+        let attributes_len = slot.attributes_len.load(Ordering::Relaxed) as usize;
+        let payload_len = slot.payload_len.load(Ordering::Relaxed) as usize;
+        let attributes_lease = SlotLease {
+            segment: self.segment.clone(),
+            sequence_number,
+            offset: 0,
+            len: attributes_len,
+        };
+        if slot.seq.load(Ordering::Acquire) != seq {
+            return ReadResult::Skipped;
+        }
+        // the lengths have been written by another process and cannot be trusted
+        if attributes_len
+            .checked_add(payload_len)
+            .is_none_or(|len| len > self.segment.options.slot_size as usize)
+        {
+            warn!(
+                attributes_len,
+                payload_len, "ignoring message that exceeds slot size"
+            );
+            return ReadResult::Skipped;
+        }
+        let attributes = match UAttributes::parse_from_bytes(attributes_lease.as_ref()) {
+            Ok(attributes) => attributes,
+            Err(e) => {
+                debug!("ignoring message with invalid attributes: {e}");
+                return ReadResult::Skipped;
+            }
+        };
+        let payload = if payload_len > 0 {
+            slot.readers.fetch_add(1, Ordering::Relaxed);
+            Some(Bytes::from_owner(SlotLease {
+                segment: self.segment.clone(),
+                sequence_number,
+                offset: attributes_lease.len,
+                len: payload_len,
+            }))
+        } else {
+            None
+        };
+        ReadResult::Message(UMessage {
+            attributes: Some(attributes).into(),
+            payload,
+            ..Default::default()
+        })
+    }
+}
+
+type Listeners = Arc<RwLock<FilterIndex<ComparableListener>>>;
+
+/// Reads messages from the ring buffer and forwards those that have matching listeners.
+///
+/// Runs until `stop` is set or the receiving end of the channel has been dropped.
+fn receive_messages(
+    mut reader: Reader,
+    listeners: Listeners,
+    messages: mpsc::UnboundedSender<UMessage>,
+    stop: Arc<AtomicBool>,
+) {
+    while !stop.load(Ordering::Acquire) {
+        let published = reader.segment.header().published.load(Ordering::Acquire);
+        match reader.try_read() {
+            ReadResult::Message(msg) => {
+                // avoid holding on to slots for messages that nobody is interested in
+                let has_listeners = listeners
+                    .read()
+                    .is_ok_and(|listeners| !listeners.find_matches_for_message(&msg).is_empty());
+                if has_listeners && messages.send(msg).is_err() {
+                    return;
+                }
+            }
+            ReadResult::Skipped => {}
+            ReadResult::Empty => reader.segment.wait(published),
+        }
+    }
+}
+
+/// A [`UTransport`] that exchanges messages with other uEntities on the same host via a
+/// POSIX shared memory segment.
+///
+/// A message sent via [`UTransport::send`] will be dispatched to all listeners registered with
+/// any of the transports that use the same segment, if the message's source and sink match the
+/// listeners' filters. This includes the listeners registered with the sending transport itself.
+///
+/// The payload of a received message references the shared memory segment directly, see the
+/// [module documentation](self) for details.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use up_rust::{shm_transport::ShmTransport, UMessageBuilder, UPayloadFormat, UTransport, UUri};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = ShmTransport::open("my-vehicle-camera").await.unwrap();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let frame = vec![0_u8; 512 * 1024];
+/// let msg = UMessageBuilder::publish(topic)
+///     .build_with_payload(frame, UPayloadFormat::UPAYLOAD_FORMAT_RAW)
+///     .unwrap();
+/// transport.send(msg).await.unwrap();
+/// # }
+/// ```
+pub struct ShmTransport {
+    segment: Arc<Segment>,
+    listeners: Listeners,
+    stop: Arc<AtomicBool>,
+    dispatcher_task: std::sync::Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ShmTransport {
+    /// Opens a named shared memory segment using the default [`ShmOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`] if the segment cannot be opened.
+    pub async fn open(name: &str) -> Result<Self, UStatus> {
+        Self::open_with_options(name, ShmOptions::default()).await
+    }
+
+    /// Opens a named shared memory segment.
+    ///
+    /// The segment is created if it does not exist yet. Otherwise, the existing segment is used
+    /// as is, i.e. its layout is determined by the transport that has created it.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the segment. Must not contain any `/` characters.
+    /// * `options` - The layout to use if the segment needs to be created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`] if the segment cannot be opened.
+    pub async fn open_with_options(name: &str, options: ShmOptions) -> Result<Self, UStatus> {
+        let segment = Segment::open(name, options)
+            .await
+            .map_err(|e| UStatus::fail_with_code(UCode::UNAVAILABLE, e.to_string()))?;
+        Ok(ShmTransport {
+            segment: Arc::new(segment),
+            listeners: Arc::new(RwLock::new(FilterIndex::default())),
+            stop: Arc::new(AtomicBool::new(false)),
+            dispatcher_task: std::sync::Mutex::new(None),
+        })
+    }
+
+    /// Removes a named shared memory segment.
+    ///
+    /// Transports that have already opened the segment can still use it. However, transports
+    /// opening a segment of the same name afterwards will use a new segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::NOT_FOUND`] if no segment of the given name exists,
+    /// * [`UCode::PERMISSION_DENIED`] if the segment may not be removed by this process,
+    /// * [`UCode::INTERNAL`] if the segment cannot be removed for any other reason.
+    pub fn remove(name: &str) -> Result<(), UStatus> {
+        let c_name = segment_name(name)
+            .map_err(|e| UStatus::fail_with_code(UCode::INVALID_ARGUMENT, e.to_string()))?;
+        // SAFETY: c_name is a valid NUL terminated string
+        if unsafe { libc::shm_unlink(c_name.as_ptr()) } == 0 {
+            return Ok(());
+        }
+        let err = io::Error::last_os_error();
+        let code = match err.raw_os_error() {
+            Some(libc::ENOENT) => UCode::NOT_FOUND,
+            Some(libc::EACCES) => UCode::PERMISSION_DENIED,
+            _ => UCode::INTERNAL,
+        };
+        Err(UStatus::fail_with_code(code, err.to_string()))
+    }
+
+    /// Starts receiving messages from the segment, unless this has already been done.
+    fn start_receiver(&self) -> Result<(), UStatus> {
+        let mut dispatcher_task = self.dispatcher_task.lock().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for receiver")
+        })?;
+        if dispatcher_task.is_some() {
+            return Ok(());
+        }
+        let (messages_tx, mut messages_rx) = mpsc::unbounded_channel::<UMessage>();
+        let reader = Reader::new(self.segment.clone());
+        let listeners = self.listeners.clone();
+        let stop = self.stop.clone();
+        thread::Builder::new()
+            .name("shm-receiver".to_string())
+            .spawn(move || receive_messages(reader, listeners, messages_tx, stop))
+            .map_err(|e| UStatus::fail_with_code(UCode::INTERNAL, e.to_string()))?;
+
+        let listeners = self.listeners.clone();
+        *dispatcher_task = Some(tokio::spawn(async move {
+            while let Some(msg) = messages_rx.recv().await {
+                let matching_listeners: Vec<ComparableListener> = listeners
+                    .read()
+                    .map(|listeners| {
+                        listeners
+                            .find_matches_for_message(&msg)
+                            .into_iter()
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                for listener in matching_listeners {
+                    listener.on_receive(msg.clone()).await;
+                }
+            }
+            info!("stopped dispatching messages from shared memory");
+        }));
+        Ok(())
+    }
+}
+
+impl Drop for ShmTransport {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Ok(Some(dispatcher_task)) = self.dispatcher_task.get_mut().map(Option::take) {
+            dispatcher_task.abort();
+        }
+    }
+}
+
+#[async_trait]
+impl UTransport for ShmTransport {
+    /// Writes a message to the shared memory segment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the message has no attributes or does not fit into a slot,
+    /// * [`UCode::RESOURCE_EXHAUSTED`] if the next slot is still referenced by a receiver or has
+    ///   already been used for a newer message by another sender.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        let Some(attributes) = message.attributes.as_ref() else {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "message has no attributes",
+            ));
+        };
+        let attributes = attributes
+            .write_to_bytes()
+            .map_err(|e| UStatus::fail_with_code(UCode::INVALID_ARGUMENT, e.to_string()))?;
+        let payload = message.payload.as_deref().unwrap_or_default();
+        self.segment.write(&attributes, payload)
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities::default()
+            .with_push_delivery(true)
+            .with_pull_delivery(false)
+            .with_message_types(&[
+                UMessageType::UMESSAGE_TYPE_PUBLISH,
+                UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                UMessageType::UMESSAGE_TYPE_REQUEST,
+                UMessageType::UMESSAGE_TYPE_RESPONSE,
+            ])
+            .with_max_message_size(self.segment.options.slot_size as usize)
+            .with_ordering(MessageOrdering::PerSender)
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        verify_filter_criteria(source_filter, sink_filter)?;
+        self.start_receiver()?;
+        let mut listeners = self.listeners.write().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })?;
+        if listeners.insert(
+            source_filter,
+            sink_filter,
+            ComparableListener::new(listener),
+        ) {
+            Ok(())
+        } else {
+            Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "listener already registered for filters",
+            ))
+        }
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let mut listeners = self.listeners.write().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })?;
+        listeners
+            .remove(
+                source_filter,
+                sink_filter,
+                &ComparableListener::new(listener),
+            )
+            .map(|_listener| ())
+            .ok_or_else(|| {
+                UStatus::fail_with_code(UCode::NOT_FOUND, "no such listener registered for filters")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{utransport::forwarding_listener, UMessageBuilder, UPayloadFormat};
+
+    /// Removes the segment when the test ends.
+    struct SegmentName(String);
+
+    impl SegmentName {
+        fn new(test: &str) -> Self {
+            SegmentName(format!("up-rust-test-{}-{test}", std::process::id()))
+        }
+    }
+
+    impl Drop for SegmentName {
+        fn drop(&mut self) {
+            let _ = ShmTransport::remove(&self.0);
+        }
+    }
+
+    const SMALL: ShmOptions = ShmOptions {
+        slot_count: 2,
+        slot_size: 4096,
+    };
+
+    #[tokio::test]
+    async fn test_messages_are_delivered_to_matching_listeners() {
+        let name = SegmentName::new("delivery");
+        let subscriber = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        let publisher = ShmTransport::open(&name.0).await.unwrap();
+        assert_eq!(publisher.segment.options, SMALL);
+
+        let (listener, mut rx) = forwarding_listener();
+        let topic_filter = UUri::try_from("//my-vehicle/A100/1/FFFF").unwrap();
+        subscriber
+            .register_listener(&topic_filter, None, listener)
+            .await
+            .unwrap();
+
+        let other_topic = UUri::try_from("//my-vehicle/B200/1/8001").unwrap();
+        let ignored = UMessageBuilder::publish(other_topic).build().unwrap();
+        publisher.send(ignored).await.unwrap();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic)
+            .build_with_payload(vec![0xAB_u8; 1000], UPayloadFormat::UPAYLOAD_FORMAT_RAW)
+            .unwrap();
+        publisher.send(msg.clone()).await.unwrap();
+
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(received, msg);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_payload_references_slot_until_dropped() {
+        let name = SegmentName::new("lease");
+        let transport = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        let (listener, mut rx) = forwarding_listener();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        transport
+            .register_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        let msg = UMessageBuilder::publish(topic)
+            .build_with_payload("hello", UPayloadFormat::UPAYLOAD_FORMAT_TEXT)
+            .unwrap();
+
+        // GIVEN a received message that is still being referenced
+        transport.send(msg.clone()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let payload = received.payload.clone().unwrap();
+        let slot_data = transport.segment.slot_data(0) as *const u8;
+        assert!(payload.as_ptr() > slot_data);
+        drop(received);
+
+        // WHEN the ring buffer wraps around to the message's slot
+        transport.send(msg.clone()).await.unwrap();
+        let _ = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        let result = transport.send(msg.clone()).await;
+
+        // THEN the slot cannot be overwritten
+        assert!(result.is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+        assert_eq!(payload, "hello");
+
+        // and can be used again once the payload has been dropped
+        drop(payload);
+        assert!(transport.send(msg.clone()).await.is_ok());
+        assert!(transport.send(msg).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_if_slot_has_been_used_for_newer_message() {
+        let name = SegmentName::new("lapped");
+        let transport = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        // GIVEN another sender that has lapped this sender while it was about to write to slot 0
+        let newer_seq = (SMALL.slot_count as u64 + 1) << 1;
+        transport
+            .segment
+            .slot(0)
+            .seq
+            .store(newer_seq, Ordering::Release);
+
+        let msg = UMessageBuilder::publish(UUri::try_from("//my-vehicle/A100/1/8001").unwrap())
+            .build()
+            .unwrap();
+        let result = transport.send(msg.clone()).await;
+
+        // THEN the message is not reported as being sent
+        assert!(result.is_err_and(|e| e.get_code() == UCode::RESOURCE_EXHAUSTED));
+        // and the slot is released again
+        assert_eq!(transport.segment.slot(0).readers.load(Ordering::Acquire), 0);
+        assert!(transport.send(msg).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_for_message_exceeding_slot_size() {
+        let name = SegmentName::new("too-large");
+        let transport = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic)
+            .build_with_payload(vec![0_u8; 5000], UPayloadFormat::UPAYLOAD_FORMAT_RAW)
+            .unwrap();
+        assert!(transport
+            .send(msg)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+
+    #[tokio::test]
+    async fn test_reader_skips_message_with_corrupt_lengths() {
+        let name = SegmentName::new("corrupt");
+        let transport = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        let mut reader = Reader::new(transport.segment.clone());
+        let msg = UMessageBuilder::publish(UUri::try_from("//my-vehicle/A100/1/8001").unwrap())
+            .build_with_payload("hello", UPayloadFormat::UPAYLOAD_FORMAT_TEXT)
+            .unwrap();
+        transport.send(msg).await.unwrap();
+
+        // GIVEN a peer that has written lengths exceeding the slot
+        let slot = transport.segment.slot(0);
+        slot.payload_len.store(u32::MAX, Ordering::Relaxed);
+
+        // THEN the message is skipped
+        assert!(matches!(reader.try_read(), ReadResult::Skipped));
+        // and the slot is released again
+        assert_eq!(slot.readers.load(Ordering::Acquire), 0);
+    }
+
+    #[tokio::test]
+    async fn test_register_listener_fails_for_invalid_filter() {
+        let name = SegmentName::new("invalid-filter");
+        let transport = ShmTransport::open_with_options(&name.0, SMALL)
+            .await
+            .unwrap();
+        let (listener, _rx) = forwarding_listener();
+        // a source filter must not be an RPC method
+        let source_filter = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+        assert!(transport
+            .register_listener(&source_filter, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+
+    #[test]
+    fn test_remove_fails_for_missing_segment() {
+        assert!(ShmTransport::remove("up-rust-test-non-existing-segment")
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+}
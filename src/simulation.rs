@@ -0,0 +1,227 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a harness for running tests against a [`LocalTransport`] in virtual time.
+
+Timeouts of RPC invocations and the expiry of messages depend on the passing of time. Tests that
+verify such behavior using real time are slow and tend to be flaky. A [`Simulation`] pauses tokio's
+time and replaces the system clock, which is used for creating message IDs and checking whether
+messages have expired, with a virtual clock. Tests can then advance time deterministically.
+
+A [`SimulatedNetwork`] connects multiple authorities by means of links with configurable latency,
+bandwidth and packet loss, which allows testing the message exchange between authorities within a
+single process.
+
+```rust
+use std::time::Duration;
+use up_rust::{simulation::Simulation, UCode, UMessageBuilder, UTransport, UUri};
+
+# #[tokio::main(flavor = "current_thread")]
+# async fn main() {
+let simulation = Simulation::start();
+let transport = simulation.transport();
+let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+transport.register_pull_filter(&topic, None, 10).await.unwrap();
+
+let msg = UMessageBuilder::publish(topic.clone()).with_ttl(500).build().unwrap();
+transport.send(msg).await.unwrap();
+simulation.advance(Duration::from_millis(500)).await;
+
+// the message has expired in the meantime
+assert!(transport
+    .receive(&topic, None)
+    .await
+    .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+# }
+```
+*/
+
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use crate::{clock, local_transport::LocalTransport};
+
+mod network;
+pub use network::{LinkConfig, NetworkEndpoint, SimulatedNetwork};
+
+/// A harness for running tests in virtual time.
+///
+/// While a simulation is running, tokio's time is paused and the clock used for creating message IDs
+/// and checking message expiry is derived from tokio's time. Time only advances when the test
+/// [advances](Self::advance) it explicitly or when tokio automatically advances it because all
+/// tasks are waiting for a timer.
+///
+/// The virtual clock is installed for the current thread only. Simulations therefore need to be
+/// run on a `current_thread` tokio runtime, which is the default for `#[tokio::test]`. Other tests
+/// running in parallel on other threads are not affected. Code that runs on other threads while
+/// the simulation is running, e.g. by means of `tokio::task::spawn_blocking`, uses the system clock
+/// for creating message IDs and checking message expiry.
+///
+/// The system clock is restored when the simulation is dropped. Tokio's time remains paused, though.
+pub struct Simulation {
+    start_time: SystemTime,
+    transport: Arc<LocalTransport>,
+}
+
+impl Simulation {
+    /// Starts a new simulation at the current system time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not invoked from within a `current_thread` tokio runtime or if tokio's time is already paused.
+    pub fn start() -> Self {
+        Self::start_at(SystemTime::now())
+    }
+
+    /// Starts a new simulation at a given point in time.
+    ///
+    /// This allows running tests that depend on the timestamps contained in message IDs in a reproducible way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if not invoked from within a `current_thread` tokio runtime or if tokio's time is already paused.
+    pub fn start_at(start_time: SystemTime) -> Self {
+        tokio::time::pause();
+        clock::install_virtual_clock(start_time);
+        Simulation {
+            start_time,
+            transport: Arc::new(LocalTransport::default().with_strict_mode(true)),
+        }
+    }
+
+    /// Gets the transport to use for exchanging messages.
+    ///
+    /// The transport runs in [strict mode](LocalTransport::with_strict_mode), i.e. it rejects and
+    /// discards expired messages.
+    pub fn transport(&self) -> Arc<LocalTransport> {
+        self.transport.clone()
+    }
+
+    /// Gets the current virtual time.
+    pub fn now(&self) -> SystemTime {
+        clock::now()
+    }
+
+    /// Gets the amount of virtual time that has passed since the simulation has been started.
+    pub fn elapsed(&self) -> Duration {
+        self.now()
+            .duration_since(self.start_time)
+            .unwrap_or_default()
+    }
+
+    /// Advances virtual time.
+    ///
+    /// All timers that expire within the given amount of time fire, e.g. RPC invocations
+    /// with a TTL shorter than the given amount of time fail with a timeout.
+    ///
+    /// Note that tokio's timers have a granularity of one millisecond, i.e. a timer might
+    /// fire up to one millisecond after its deadline.
+    pub async fn advance(&self, duration: Duration) {
+        // give tasks that have been spawned in the meantime the chance to start their timers
+        tokio::task::yield_now().await;
+        tokio::time::advance(duration).await;
+        // and give tasks whose timers have fired the chance to run
+        tokio::task::yield_now().await;
+    }
+}
+
+impl Drop for Simulation {
+    fn drop(&mut self) {
+        clock::uninstall_virtual_clock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{UCode, UMessageBuilder, UTransport, UUri, UUID};
+
+    #[tokio::test]
+    async fn test_messages_expire_in_virtual_time() {
+        let simulation =
+            Simulation::start_at(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000));
+        let transport = simulation.transport();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        transport
+            .register_pull_filter(&topic, None, 10)
+            .await
+            .unwrap();
+
+        // message IDs are created using the virtual clock
+        assert_eq!(UUID::build().get_time(), Some(1_000_000_000));
+
+        for _ in 0..2 {
+            let msg = UMessageBuilder::publish(topic.clone())
+                .with_ttl(500)
+                .build()
+                .unwrap();
+            transport.send(msg).await.unwrap();
+        }
+
+        simulation.advance(Duration::from_millis(499)).await;
+        assert!(transport.receive(&topic, None).await.is_ok());
+        simulation.advance(Duration::from_millis(1)).await;
+        assert!(transport
+            .receive(&topic, None)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+        assert_eq!(simulation.elapsed(), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_virtual_clock_is_used_on_simulation_thread_only() {
+        let _simulation =
+            Simulation::start_at(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000));
+        assert_eq!(UUID::build().get_time(), Some(1_000_000_000));
+
+        let other_thread_time = tokio::task::spawn_blocking(|| UUID::build().get_time())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(other_thread_time > 1_000_000_000);
+    }
+
+    #[cfg(feature = "communication")]
+    #[tokio::test]
+    async fn test_rpc_invocation_times_out_in_virtual_time() {
+        use crate::{
+            communication::{CallOptions, InMemoryRpcClient, RpcClient, ServiceInvocationError},
+            StaticUriProvider,
+        };
+
+        let simulation = Simulation::start();
+        let client = InMemoryRpcClient::new(
+            simulation.transport(),
+            Arc::new(StaticUriProvider::new("my-vehicle", 0x2000, 0x01)),
+        )
+        .await
+        .unwrap();
+
+        // no service is available for handling the request
+        let method = UUri::try_from("//my-vehicle/1000/1/1").unwrap();
+        let result = client
+            .invoke_method(
+                method,
+                CallOptions::for_rpc_request(60_000, None, None, None),
+                None,
+            )
+            .await;
+
+        assert!(result.is_err_and(|e| matches!(e, ServiceInvocationError::DeadlineExceeded)));
+        assert!(simulation.elapsed() >= Duration::from_secs(60));
+    }
+}
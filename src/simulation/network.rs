@@ -0,0 +1,538 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use protobuf::Message;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use tokio::time::Instant;
+use tracing::debug;
+
+use crate::{
+    local_transport::LocalTransport, TransportCapabilities, UCode, UListener, UMessage, UStatus,
+    UTransport, UUri,
+};
+
+/// The characteristics of a (directed) link between two authorities of a [`SimulatedNetwork`].
+///
+/// The default value describes a perfect link without any latency, bandwidth limit or packet loss.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinkConfig {
+    /// The time that it takes for a message to travel from one authority to the other.
+    pub latency: Duration,
+    /// The number of bytes that can be transmitted per second, or `None` for unlimited bandwidth.
+    ///
+    /// Messages sent via the same link are transmitted one after the other, i.e. a message
+    /// needs to wait for all messages sent before to be transmitted completely.
+    pub bandwidth: Option<u64>,
+    /// The probability of a message to get lost, from 0.0 (never) to 1.0 (always).
+    pub loss_probability: f64,
+}
+
+/// The state of a directed link between two authorities.
+#[derive(Default)]
+struct Link {
+    config: LinkConfig,
+    // the point in time at which the link has finished transmitting all messages sent so far
+    busy_until: Option<Instant>,
+}
+
+struct NetworkState {
+    endpoints: HashMap<String, Arc<LocalTransport>>,
+    links: HashMap<(String, String), Link>,
+    default_link: LinkConfig,
+    partitions: HashSet<(String, String)>,
+    rng: StdRng,
+}
+
+impl NetworkState {
+    fn is_partitioned(&self, from: &str, to: &str) -> bool {
+        self.partitions.contains(&partition_key(from, to))
+    }
+
+    /// Determines the point in time at which a message will arrive at its destination.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the message gets lost.
+    fn schedule_transmission(&mut self, from: &str, to: &str, size: u64) -> Option<Instant> {
+        if self.is_partitioned(from, to) {
+            debug!(from, to, "authorities are partitioned, discarding message");
+            return None;
+        }
+        let default_link = self.default_link;
+        let link = self
+            .links
+            .entry((from.to_string(), to.to_string()))
+            .or_insert_with(|| Link {
+                config: default_link,
+                busy_until: None,
+            });
+        let config = link.config;
+        let now = Instant::now();
+        let transmission_start = link
+            .busy_until
+            .map_or(now, |busy_until| busy_until.max(now));
+        let transmission_time = config.bandwidth.map_or(Duration::ZERO, |bandwidth| {
+            Duration::from_secs_f64(size as f64 / bandwidth.max(1) as f64)
+        });
+        let transmission_end = transmission_start + transmission_time;
+        link.busy_until = Some(transmission_end);
+        if self.rng.gen_bool(config.loss_probability.clamp(0.0, 1.0)) {
+            debug!(from, to, "message got lost on link");
+            return None;
+        }
+        Some(transmission_end + config.latency)
+    }
+}
+
+fn partition_key(a: &str, b: &str) -> (String, String) {
+    if a <= b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+/// A simulated network that connects multiple authorities within a single process.
+///
+/// Each authority is represented by a [`NetworkEndpoint`], which is a [`UTransport`] that uEntities
+/// of the authority can use for exchanging messages with uEntities of the same and of other authorities.
+/// Messages are routed based on the authority name of their sink address. Publish messages, which do
+/// not have a sink address, are distributed to all authorities.
+///
+/// Messages exchanged between different authorities travel via directed links, whose latency,
+/// bandwidth and packet loss can be [configured](Self::set_link). Authorities can also be
+/// [partitioned](Self::partition) from each other. Messages exchanged between uEntities of the same
+/// authority are delivered without any delay.
+///
+/// Delays are implemented using tokio's timers. The network can therefore be combined with a
+/// [`Simulation`](super::Simulation) for running tests in virtual time.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use up_rust::{
+///     simulation::{LinkConfig, SimulatedNetwork, Simulation},
+///     UCode, UMessageBuilder, UTransport, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let simulation = Simulation::start();
+/// let network = SimulatedNetwork::default();
+/// let vehicle = network.add_authority("my-vehicle").unwrap();
+/// let cloud = network.add_authority("my-cloud").unwrap();
+/// network.set_link(
+///     "my-vehicle",
+///     "my-cloud",
+///     LinkConfig {
+///         latency: Duration::from_millis(100),
+///         ..Default::default()
+///     },
+/// );
+///
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// cloud.transport().register_pull_filter(&topic, None, 10).await.unwrap();
+/// vehicle.send(UMessageBuilder::publish(topic.clone()).build().unwrap()).await.unwrap();
+///
+/// simulation.advance(Duration::from_millis(50)).await;
+/// assert!(cloud.receive(&topic, None).await.is_err());
+/// simulation.advance(Duration::from_millis(60)).await;
+/// assert!(cloud.receive(&topic, None).await.is_ok());
+/// # }
+/// ```
+pub struct SimulatedNetwork {
+    state: Arc<Mutex<NetworkState>>,
+}
+
+impl Default for SimulatedNetwork {
+    fn default() -> Self {
+        SimulatedNetwork {
+            state: Arc::new(Mutex::new(NetworkState {
+                endpoints: HashMap::new(),
+                links: HashMap::new(),
+                default_link: LinkConfig::default(),
+                partitions: HashSet::new(),
+                rng: StdRng::from_entropy(),
+            })),
+        }
+    }
+}
+
+impl SimulatedNetwork {
+    /// Sets the characteristics of all links that have not been [configured explicitly](Self::set_link).
+    pub fn with_default_link(self, config: LinkConfig) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.default_link = config;
+        }
+        self
+    }
+
+    /// Seeds the pseudo random number generator used for deciding about lost messages.
+    pub fn with_seed(self, seed: u64) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.rng = StdRng::seed_from_u64(seed);
+        }
+        self
+    }
+
+    /// Adds an authority to the network.
+    ///
+    /// # Returns
+    ///
+    /// The transport that uEntities of the authority can use for exchanging messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::ALREADY_EXISTS`] if the network already contains the authority.
+    pub fn add_authority(&self, authority: &str) -> Result<Arc<NetworkEndpoint>, UStatus> {
+        let mut state = lock_state(&self.state)?;
+        if state.endpoints.contains_key(authority) {
+            return Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                format!("network already contains authority {authority}"),
+            ));
+        }
+        let transport = Arc::new(LocalTransport::default().with_strict_mode(true));
+        state
+            .endpoints
+            .insert(authority.to_string(), transport.clone());
+        Ok(Arc::new(NetworkEndpoint {
+            authority: authority.to_string(),
+            transport,
+            network: self.state.clone(),
+        }))
+    }
+
+    /// Sets the characteristics of the link that messages travel on from one authority to another.
+    ///
+    /// Links are directed, i.e. the characteristics of the link in the opposite direction are not changed.
+    pub fn set_link(&self, from: &str, to: &str, config: LinkConfig) {
+        if let Ok(mut state) = self.state.lock() {
+            state
+                .links
+                .entry((from.to_string(), to.to_string()))
+                .or_default()
+                .config = config;
+        }
+    }
+
+    /// Separates two authorities from each other.
+    ///
+    /// All messages exchanged between the authorities are lost until the partition is [healed](Self::heal).
+    pub fn partition(&self, authority_a: &str, authority_b: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state
+                .partitions
+                .insert(partition_key(authority_a, authority_b));
+        }
+    }
+
+    /// Reconnects two authorities that have been [partitioned](Self::partition) before.
+    pub fn heal(&self, authority_a: &str, authority_b: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state
+                .partitions
+                .remove(&partition_key(authority_a, authority_b));
+        }
+    }
+}
+
+fn lock_state(state: &Mutex<NetworkState>) -> Result<MutexGuard<'_, NetworkState>, UStatus> {
+    state.lock().map_err(|_e| {
+        UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for network state")
+    })
+}
+
+/// A [`UTransport`] that connects the uEntities of an authority to a [`SimulatedNetwork`].
+///
+/// Listeners are registered with a [`LocalTransport`] running in [strict mode](LocalTransport::with_strict_mode),
+/// which receives all messages that arrive at the authority. Consequently, messages that expire while
+/// traveling through the network are discarded.
+pub struct NetworkEndpoint {
+    authority: String,
+    transport: Arc<LocalTransport>,
+    network: Arc<Mutex<NetworkState>>,
+}
+
+impl NetworkEndpoint {
+    /// Gets the name of the authority that this endpoint belongs to.
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
+    /// Gets the transport that receives all messages arriving at this endpoint's authority.
+    ///
+    /// This can be used for registering pull filters, for example.
+    pub fn transport(&self) -> Arc<LocalTransport> {
+        self.transport.clone()
+    }
+
+    /// Determines the endpoints that a message needs to be delivered to.
+    fn destinations(
+        &self,
+        message: &UMessage,
+    ) -> Result<Vec<(String, Arc<LocalTransport>)>, UStatus> {
+        let state = lock_state(&self.network)?;
+        match message.sink() {
+            Some(sink) => {
+                let authority = if sink.authority_name.is_empty() {
+                    &self.authority
+                } else {
+                    &sink.authority_name
+                };
+                state
+                    .endpoints
+                    .get(authority)
+                    .map(|transport| vec![(authority.to_owned(), transport.clone())])
+                    .ok_or_else(|| {
+                        UStatus::fail_with_code(
+                            UCode::NOT_FOUND,
+                            format!("network does not contain authority {authority}"),
+                        )
+                    })
+            }
+            None => Ok(state
+                .endpoints
+                .iter()
+                .map(|(authority, transport)| (authority.to_owned(), transport.clone()))
+                .collect()),
+        }
+    }
+}
+
+#[async_trait]
+impl UTransport for NetworkEndpoint {
+    /// Sends a message to the authority of the message's sink address or, for publish messages, to all authorities.
+    ///
+    /// Messages sent to other authorities are delivered asynchronously, i.e. this function returns right away.
+    /// Messages that get lost on their way are silently discarded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::NOT_FOUND`] if the network does not contain the authority of the
+    /// message's sink address.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        let size = message.compute_size();
+        let mut result = Ok(());
+        for (authority, transport) in self.destinations(&message)? {
+            if authority == self.authority {
+                if let Err(e) = transport.send(message.clone()).await {
+                    result = Err(e);
+                }
+                continue;
+            }
+            let arrival =
+                lock_state(&self.network)?.schedule_transmission(&self.authority, &authority, size);
+            if let Some(arrival) = arrival {
+                let message = message.clone();
+                tokio::spawn(async move {
+                    tokio::time::sleep_until(arrival).await;
+                    if let Err(e) = transport.send(message).await {
+                        debug!(authority, "failed to deliver message: {}", e.get_message());
+                    }
+                });
+            }
+        }
+        result
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        self.transport.capabilities()
+    }
+
+    async fn receive(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<UMessage, UStatus> {
+        self.transport.receive(source_filter, sink_filter).await
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.transport
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.transport
+            .unregister_listener(source_filter, sink_filter, listener)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{simulation::Simulation, UMessageBuilder, UPayloadFormat};
+
+    fn topic() -> UUri {
+        UUri::try_from("//my-vehicle/A100/1/8001").unwrap()
+    }
+
+    fn publish_message(payload_size: usize) -> UMessage {
+        UMessageBuilder::publish(topic())
+            .build_with_payload(vec![0u8; payload_size], UPayloadFormat::UPAYLOAD_FORMAT_RAW)
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_delayed_by_link_latency_and_bandwidth() {
+        let simulation = Simulation::start();
+        let network = SimulatedNetwork::default();
+        let vehicle = network.add_authority("my-vehicle").unwrap();
+        let cloud = network.add_authority("my-cloud").unwrap();
+        assert!(network
+            .add_authority("my-cloud")
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+        network.set_link(
+            "my-vehicle",
+            "my-cloud",
+            LinkConfig {
+                latency: Duration::from_millis(100),
+                bandwidth: Some(10_000),
+                loss_probability: 0.0,
+            },
+        );
+        vehicle
+            .transport()
+            .register_pull_filter(&topic(), None, 10)
+            .await
+            .unwrap();
+        cloud
+            .transport()
+            .register_pull_filter(&topic(), None, 10)
+            .await
+            .unwrap();
+
+        // each message takes roughly 100ms to be transmitted
+        vehicle.send(publish_message(1_000)).await.unwrap();
+        vehicle.send(publish_message(1_000)).await.unwrap();
+
+        // local uEntities receive the messages right away
+        assert!(vehicle.receive(&topic(), None).await.is_ok());
+        assert!(vehicle.receive(&topic(), None).await.is_ok());
+
+        simulation.advance(Duration::from_millis(150)).await;
+        assert!(cloud.receive(&topic(), None).await.is_err());
+        simulation.advance(Duration::from_millis(70)).await;
+        assert!(cloud.receive(&topic(), None).await.is_ok());
+        assert!(cloud.receive(&topic(), None).await.is_err());
+        simulation.advance(Duration::from_millis(100)).await;
+        assert!(cloud.receive(&topic(), None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_lost_between_partitioned_authorities() {
+        let simulation = Simulation::start();
+        let network = SimulatedNetwork::default().with_seed(42);
+        let vehicle = network.add_authority("my-vehicle").unwrap();
+        let cloud = network.add_authority("my-cloud").unwrap();
+        cloud
+            .transport()
+            .register_pull_filter(&topic(), None, 10)
+            .await
+            .unwrap();
+
+        network.partition("my-cloud", "my-vehicle");
+        vehicle.send(publish_message(10)).await.unwrap();
+        simulation.advance(Duration::from_millis(10)).await;
+        assert!(cloud.receive(&topic(), None).await.is_err());
+
+        network.heal("my-vehicle", "my-cloud");
+        vehicle.send(publish_message(10)).await.unwrap();
+        simulation.advance(Duration::from_millis(10)).await;
+        assert!(cloud.receive(&topic(), None).await.is_ok());
+
+        network.set_link(
+            "my-vehicle",
+            "my-cloud",
+            LinkConfig {
+                loss_probability: 1.0,
+                ..Default::default()
+            },
+        );
+        vehicle.send(publish_message(10)).await.unwrap();
+        simulation.advance(Duration::from_millis(10)).await;
+        assert!(cloud.receive(&topic(), None).await.is_err());
+    }
+
+    #[cfg(feature = "communication")]
+    #[tokio::test]
+    async fn test_rpc_across_authorities() {
+        use crate::{
+            communication::{
+                CallOptions, InMemoryRpcClient, InMemoryRpcServer, MockRequestHandler, RpcClient,
+                RpcServer,
+            },
+            StaticUriProvider,
+        };
+
+        let simulation = Simulation::start();
+        let network = SimulatedNetwork::default().with_default_link(LinkConfig {
+            latency: Duration::from_millis(100),
+            ..Default::default()
+        });
+        let vehicle = network.add_authority("my-vehicle").unwrap();
+        let cloud = network.add_authority("my-cloud").unwrap();
+
+        let mut request_handler = MockRequestHandler::new();
+        request_handler
+            .expect_handle_request()
+            .once()
+            .returning(|_resource_id, _attributes, _payload| Ok(None));
+        let server = InMemoryRpcServer::new(
+            cloud.clone(),
+            Arc::new(StaticUriProvider::new("my-cloud", 0x1000, 0x01)),
+        );
+        server
+            .register_endpoint(None, 0x0001, Arc::new(request_handler))
+            .await
+            .unwrap();
+        let client = InMemoryRpcClient::new(
+            vehicle,
+            Arc::new(StaticUriProvider::new("my-vehicle", 0x2000, 0x01)),
+        )
+        .await
+        .unwrap();
+
+        let result = client
+            .invoke_method(
+                UUri::try_from("//my-cloud/1000/1/1").unwrap(),
+                CallOptions::for_rpc_request(5_000, None, None, None),
+                None,
+            )
+            .await;
+        assert!(result.is_ok());
+        assert!(simulation.elapsed() >= Duration::from_millis(200));
+    }
+}
@@ -0,0 +1,987 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Building blocks for transports that exchange messages via byte streams (e.g. sockets) by means of a hub.
+
+Clients connect to the hub and register their listeners' filters with it. The hub then forwards
+all messages it receives to those clients that have registered matching filters.
+
+All data is exchanged in _frames_, each consisting of
+* the length of the rest of the frame as a 32 bit unsigned integer in big endian byte order,
+* a single byte indicating the kind of frame,
+* the protobuf encoding of the frame's content.
+*/
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, RwLock,
+    },
+    time::Duration,
+};
+
+use protobuf::Message;
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    sync::{mpsc, oneshot},
+    task::{AbortHandle, JoinHandle},
+};
+use tracing::{debug, info};
+
+use crate::{
+    up_core_api::uri::UUriBatch, utransport::FilterIndex, ComparableListener, ConnectionState,
+    ConnectionStateNotifier, UCode, UListener, UMessage, UStatus, UUri,
+};
+
+/// The maximum size of a frame that is being accepted.
+pub(crate) const MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// The maximum amount of time to wait for the hub's reply to a (un)register request.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+const KIND_MESSAGE: u8 = 0x01;
+const KIND_REGISTER: u8 = 0x02;
+const KIND_UNREGISTER: u8 = 0x03;
+const KIND_STATUS: u8 = 0x04;
+
+/// The source and sink filter patterns that a listener has been registered for.
+pub(crate) type Filters = (UUri, Option<UUri>);
+
+/// The units of data being exchanged between hub and clients.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Frame {
+    /// A message to be forwarded to all clients with matching filters.
+    Message(UMessage),
+    /// A request to forward messages matching the given filters to the client.
+    Register(Filters),
+    /// A request to stop forwarding messages matching the given filters to the client.
+    Unregister(Filters),
+    /// The outcome of processing a (un)register request.
+    Status(UStatus),
+}
+
+impl Frame {
+    fn encode_filters((source_filter, sink_filter): &Filters) -> protobuf::Result<Vec<u8>> {
+        let uris = std::iter::once(source_filter)
+            .chain(sink_filter.as_ref())
+            .cloned()
+            .collect();
+        UUriBatch {
+            uris,
+            ..Default::default()
+        }
+        .write_to_bytes()
+    }
+
+    fn decode_filters(bytes: &[u8]) -> io::Result<Filters> {
+        let batch = UUriBatch::parse_from_bytes(bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mut uris = batch.uris.into_iter();
+        match (uris.next(), uris.next(), uris.next()) {
+            (Some(source_filter), sink_filter, None) => Ok((source_filter, sink_filter)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "filters must consist of one or two URIs",
+            )),
+        }
+    }
+
+    /// Encodes this frame into the bytes to be written to a stream, including the length prefix.
+    pub(crate) fn encode(&self) -> io::Result<Vec<u8>> {
+        let (kind, content) = match self {
+            Frame::Message(msg) => (KIND_MESSAGE, msg.write_to_bytes()),
+            Frame::Register(filters) => (KIND_REGISTER, Self::encode_filters(filters)),
+            Frame::Unregister(filters) => (KIND_UNREGISTER, Self::encode_filters(filters)),
+            Frame::Status(status) => (KIND_STATUS, status.write_to_bytes()),
+        };
+        let content = content.map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        if content.len() >= MAX_FRAME_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "frame exceeds maximum size",
+            ));
+        }
+        let mut bytes = Vec::with_capacity(content.len() + 5);
+        // cannot overflow because the frame size is limited
+        bytes.extend_from_slice(&(content.len() as u32 + 1).to_be_bytes());
+        bytes.push(kind);
+        bytes.extend_from_slice(&content);
+        Ok(bytes)
+    }
+
+    /// Decodes a frame from its kind and content.
+    pub(crate) fn decode(kind: u8, content: &[u8]) -> io::Result<Frame> {
+        match kind {
+            KIND_MESSAGE => UMessage::parse_from_bytes(content)
+                .map(Frame::Message)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            KIND_REGISTER => Self::decode_filters(content).map(Frame::Register),
+            KIND_UNREGISTER => Self::decode_filters(content).map(Frame::Unregister),
+            KIND_STATUS => UStatus::parse_from_bytes(content)
+                .map(Frame::Status)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported frame kind [{kind}]"),
+            )),
+        }
+    }
+}
+
+/// Writes a frame to a stream.
+pub(crate) async fn write_frame<W: AsyncWrite + Unpin + ?Sized>(
+    writer: &mut W,
+    frame: &Frame,
+) -> io::Result<()> {
+    let bytes = frame.encode()?;
+    writer.write_all(&bytes).await?;
+    writer.flush().await
+}
+
+/// Reads the next frame from a stream.
+///
+/// # Returns
+///
+/// `None` if the stream has been closed by the peer.
+pub(crate) async fn read_frame<R: AsyncRead + Unpin + ?Sized>(
+    reader: &mut R,
+) -> io::Result<Option<Frame>> {
+    let mut length = [0_u8; 4];
+    match reader.read_exact(&mut length).await {
+        Ok(_) => {}
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    }
+    let length = u32::from_be_bytes(length) as usize;
+    if length == 0 || length > MAX_FRAME_SIZE {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid frame length [{length}]"),
+        ));
+    }
+    let mut buf = vec![0_u8; length];
+    reader.read_exact(&mut buf).await?;
+    Frame::decode(buf[0], &buf[1..]).map(Some)
+}
+
+/// Maps an I/O error to a status that can be returned by a transport.
+pub(crate) fn unavailable(err: io::Error) -> UStatus {
+    UStatus::fail_with_code(UCode::UNAVAILABLE, err.to_string())
+}
+
+struct HubConnection {
+    frames: mpsc::UnboundedSender<Frame>,
+    filters: HashSet<Filters>,
+    reader_task: Option<AbortHandle>,
+}
+
+#[derive(Default)]
+struct HubState {
+    routes: FilterIndex<u64>,
+    connections: HashMap<u64, HubConnection>,
+}
+
+/// Forwards messages between connected clients based on the filters that they have registered.
+#[derive(Default)]
+pub(crate) struct Hub {
+    state: Mutex<HubState>,
+    next_connection_id: AtomicU64,
+}
+
+impl Hub {
+    /// Serves a client that has connected to the hub.
+    ///
+    /// Spawns tasks for reading frames from and writing frames to the given stream.
+    /// The tasks end when the client closes the connection.
+    pub(crate) fn serve<S>(self: &Arc<Self>, stream: S)
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let connection_id = self.next_connection_id.fetch_add(1, Ordering::Relaxed);
+        let (mut reader, mut writer) = tokio::io::split(stream);
+        let (frames_tx, mut frames_rx) = mpsc::unbounded_channel();
+        if let Ok(mut state) = self.state.lock() {
+            state.connections.insert(
+                connection_id,
+                HubConnection {
+                    frames: frames_tx,
+                    filters: HashSet::new(),
+                    reader_task: None,
+                },
+            );
+        }
+
+        tokio::spawn(async move {
+            while let Some(frame) = frames_rx.recv().await {
+                if let Err(e) = write_frame(&mut writer, &frame).await {
+                    debug!(connection_id, "failed to write frame to client: {e}");
+                    break;
+                }
+            }
+        });
+
+        let hub = self.clone();
+        let reader_task = tokio::spawn(async move {
+            loop {
+                match read_frame(&mut reader).await {
+                    Ok(Some(frame)) => hub.process_frame(connection_id, frame),
+                    Ok(None) => break,
+                    Err(e) => {
+                        info!(connection_id, "closing connection to client: {e}");
+                        break;
+                    }
+                }
+            }
+            hub.remove_connection(connection_id);
+        });
+        if let Ok(mut state) = self.state.lock() {
+            if let Some(connection) = state.connections.get_mut(&connection_id) {
+                connection.reader_task = Some(reader_task.abort_handle());
+            }
+        }
+    }
+
+    /// Closes the connections to all clients.
+    pub(crate) fn shutdown(&self) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.routes = FilterIndex::default();
+        // dropping the frame senders ends the writer tasks
+        for (_id, connection) in state.connections.drain() {
+            if let Some(reader_task) = connection.reader_task {
+                reader_task.abort();
+            }
+        }
+    }
+
+    fn process_frame(&self, connection_id: u64, frame: Frame) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        match frame {
+            Frame::Message(msg) => {
+                for destination in state.routes.find_matches_for_message(&msg) {
+                    if let Some(connection) = state.connections.get(destination) {
+                        let _ = connection.frames.send(Frame::Message(msg.clone()));
+                    }
+                }
+            }
+            Frame::Register(filters) => {
+                let status = if state
+                    .routes
+                    .insert(&filters.0, filters.1.as_ref(), connection_id)
+                {
+                    if let Some(connection) = state.connections.get_mut(&connection_id) {
+                        connection.filters.insert(filters);
+                    }
+                    UStatus::ok()
+                } else {
+                    UStatus::fail_with_code(
+                        UCode::ALREADY_EXISTS,
+                        "filters already registered for client",
+                    )
+                };
+                state.reply(connection_id, status);
+            }
+            Frame::Unregister(filters) => {
+                let status = if state
+                    .routes
+                    .remove(&filters.0, filters.1.as_ref(), &connection_id)
+                    .is_some()
+                {
+                    if let Some(connection) = state.connections.get_mut(&connection_id) {
+                        connection.filters.remove(&filters);
+                    }
+                    UStatus::ok()
+                } else {
+                    UStatus::fail_with_code(UCode::NOT_FOUND, "filters not registered for client")
+                };
+                state.reply(connection_id, status);
+            }
+            Frame::Status(_) => {
+                debug!(
+                    connection_id,
+                    "ignoring unexpected status frame from client"
+                );
+            }
+        }
+    }
+
+    fn remove_connection(&self, connection_id: u64) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if let Some(connection) = state.connections.remove(&connection_id) {
+            for (source_filter, sink_filter) in connection.filters {
+                state
+                    .routes
+                    .remove(&source_filter, sink_filter.as_ref(), &connection_id);
+            }
+        }
+    }
+}
+
+impl HubState {
+    fn reply(&self, connection_id: u64, status: UStatus) {
+        if let Some(connection) = self.connections.get(&connection_id) {
+            let _ = connection.frames.send(Frame::Status(status));
+        }
+    }
+}
+
+/// The state of a [`HubClient`] that is shared with its background tasks.
+struct ClientState {
+    listeners: RwLock<FilterIndex<ComparableListener>>,
+    // the filters that have been registered with the hub
+    hub_filters: Mutex<HashSet<Filters>>,
+    // the writing half of the current connection to the hub
+    writer: tokio::sync::Mutex<Option<Box<dyn AsyncWrite + Send + Unpin>>>,
+    // the senders for the replies to (un)register requests, in the order of the requests
+    pending_replies: Mutex<VecDeque<oneshot::Sender<UStatus>>>,
+    connection: ConnectionStateNotifier,
+    // the state to change to when the connection to the hub has been lost
+    state_when_lost: ConnectionState,
+    // the task reading from the current connection to the hub
+    reader_task: Mutex<Option<AbortHandle>>,
+    // serializes (un)registration of listeners
+    registration_lock: tokio::sync::Mutex<()>,
+    messages: mpsc::UnboundedSender<UMessage>,
+}
+
+impl ClientState {
+    fn take_pending_reply(&self) -> Option<oneshot::Sender<UStatus>> {
+        self.pending_replies
+            .lock()
+            .ok()
+            .and_then(|mut pending| pending.pop_front())
+    }
+
+    fn disconnected(&self) {
+        self.connection.set_state(self.state_when_lost);
+        if let Ok(mut pending) = self.pending_replies.lock() {
+            // dropping the senders makes the requests fail
+            pending.clear();
+        }
+    }
+
+    /// Starts using a stream that is connected to the hub and reports the connection
+    /// as being established.
+    ///
+    /// # Returns
+    ///
+    /// A handle for the task reading from the stream. The task ends when the connection is lost.
+    async fn attach<S>(self: &Arc<Self>, stream: S) -> JoinHandle<()>
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let reader_task = self.start_reading(stream).await;
+        self.connection.set_state(ConnectionState::Connected);
+        reader_task
+    }
+
+    /// Starts using a stream that is connected to the hub, without changing the connection state.
+    ///
+    /// Frames can be exchanged with the hub by means of [`Self::send_request`] before the
+    /// connection is reported as being established.
+    ///
+    /// # Returns
+    ///
+    /// A handle for the task reading from the stream. The task ends when the connection is lost.
+    async fn start_reading<S>(self: &Arc<Self>, stream: S) -> JoinHandle<()>
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (mut reader, writer) = tokio::io::split(stream);
+        *self.writer.lock().await = Some(Box::new(writer));
+
+        let state = self.clone();
+        let reader_task = tokio::spawn(async move {
+            loop {
+                match read_frame(&mut reader).await {
+                    Ok(Some(Frame::Message(msg))) => {
+                        let _ = state.messages.send(msg);
+                    }
+                    Ok(Some(Frame::Status(status))) => {
+                        if let Some(reply) = state.take_pending_reply() {
+                            let _ = reply.send(status);
+                        }
+                    }
+                    Ok(Some(_)) => {
+                        debug!("ignoring unexpected frame from hub");
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        info!("closing connection to hub: {e}");
+                        break;
+                    }
+                }
+            }
+            state.disconnected();
+        });
+        if let Ok(mut current_reader_task) = self.reader_task.lock() {
+            *current_reader_task = Some(reader_task.abort_handle());
+        }
+        reader_task
+    }
+
+    fn check_connected(&self) -> Result<(), UStatus> {
+        if self.connection.state() == ConnectionState::Connected {
+            Ok(())
+        } else {
+            Err(UStatus::fail_with_code(
+                UCode::UNAVAILABLE,
+                "connection to hub has been lost",
+            ))
+        }
+    }
+
+    /// Stops using the current connection to the hub.
+    #[cfg(feature = "tcp")]
+    async fn drop_connection(&self, reader_task: JoinHandle<()>) {
+        reader_task.abort();
+        let _ = reader_task.await;
+        *self.writer.lock().await = None;
+        self.disconnected();
+    }
+
+    /// Writes a frame to the current connection, if the connection has been established.
+    async fn write(&self, frame: &Frame) -> Result<(), UStatus> {
+        self.check_connected()?;
+        self.write_frame(frame, None).await
+    }
+
+    /// Writes a frame to the current connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `reply` - The sender to use for forwarding the hub's reply to the frame, if any.
+    async fn write_frame(
+        &self,
+        frame: &Frame,
+        reply: Option<oneshot::Sender<UStatus>>,
+    ) -> Result<(), UStatus> {
+        let mut writer = self.writer.lock().await;
+        let Some(writer) = writer.as_mut() else {
+            return Err(UStatus::fail_with_code(
+                UCode::UNAVAILABLE,
+                "not connected to hub",
+            ));
+        };
+        if let Some(reply) = reply {
+            // the reply must be expected before the request is written
+            // because the reader task might receive the reply right away
+            if let Ok(mut pending) = self.pending_replies.lock() {
+                pending.push_back(reply);
+            }
+        }
+        write_frame(writer, frame).await.map_err(unavailable)
+    }
+
+    /// Sends a (un)register request to the hub and waits for the reply, if the connection
+    /// has been established.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`] if the connection to the hub has been lost,
+    /// or any of the errors returned by [`Self::send_request`].
+    async fn request(&self, frame: Frame) -> Result<(), UStatus> {
+        self.check_connected()?;
+        self.send_request(frame).await
+    }
+
+    /// Sends a (un)register request to the hub and waits for the reply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::DEADLINE_EXCEEDED`] if the hub does not reply within
+    /// [`REQUEST_TIMEOUT`], or the error that the hub has replied with.
+    async fn send_request(&self, frame: Frame) -> Result<(), UStatus> {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        self.write_frame(&frame, Some(reply_tx)).await?;
+        // the reply sender remains queued if the request times out, so that a late reply
+        // does not get mistaken for the reply to a subsequent request
+        let status = tokio::time::timeout(REQUEST_TIMEOUT, reply_rx)
+            .await
+            .map_err(|_elapsed| {
+                UStatus::fail_with_code(UCode::DEADLINE_EXCEEDED, "hub did not reply in time")
+            })?
+            .map_err(|_| {
+                UStatus::fail_with_code(UCode::UNAVAILABLE, "connection to hub has been lost")
+            })?;
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(status)
+        }
+    }
+
+    /// Registers all filters that have been registered with the hub before
+    /// over the current connection.
+    #[cfg(feature = "tcp")]
+    async fn restore_hub_filters(&self) -> Result<(), UStatus> {
+        let registered_filters: Vec<Filters> = self
+            .hub_filters
+            .lock()
+            .map(|filters| filters.iter().cloned().collect())
+            .unwrap_or_default();
+        for filters in registered_filters {
+            self.send_request(Frame::Register(filters)).await?;
+        }
+        Ok(())
+    }
+
+    fn is_registered_with_hub(&self, filters: &Filters) -> bool {
+        self.hub_filters
+            .lock()
+            .is_ok_and(|hub_filters| hub_filters.contains(filters))
+    }
+
+    fn has_listeners_for(&self, source_filter: &UUri, sink_filter: Option<&UUri>) -> bool {
+        self.listeners.read().is_ok_and(|listeners| {
+            listeners
+                .get(source_filter, sink_filter)
+                .is_some_and(|values| !values.is_empty())
+        })
+    }
+}
+
+/// A client that exchanges messages with other clients via a [`Hub`].
+pub(crate) struct HubClient {
+    state: Arc<ClientState>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+impl HubClient {
+    fn create(state_when_lost: ConnectionState) -> (Self, Arc<ClientState>) {
+        let (messages_tx, mut messages_rx) = mpsc::unbounded_channel::<UMessage>();
+        let state = Arc::new(ClientState {
+            listeners: RwLock::new(FilterIndex::default()),
+            hub_filters: Mutex::new(HashSet::new()),
+            writer: tokio::sync::Mutex::new(None),
+            pending_replies: Mutex::new(VecDeque::new()),
+            connection: ConnectionStateNotifier::new(ConnectionState::Disconnected),
+            state_when_lost,
+            reader_task: Mutex::new(None),
+            registration_lock: tokio::sync::Mutex::new(()),
+            messages: messages_tx,
+        });
+
+        // messages are dispatched by a separate task so that listeners can
+        // (un)register other listeners while processing a message
+        let dispatcher_state = state.clone();
+        let dispatcher_task = tokio::spawn(async move {
+            while let Some(msg) = messages_rx.recv().await {
+                let listeners: Vec<ComparableListener> = dispatcher_state
+                    .listeners
+                    .read()
+                    .map(|listeners| {
+                        listeners
+                            .find_matches_for_message(&msg)
+                            .into_iter()
+                            .cloned()
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                for listener in listeners {
+                    listener.on_receive(msg.clone()).await;
+                }
+            }
+        });
+
+        let client = HubClient {
+            state: state.clone(),
+            tasks: vec![dispatcher_task],
+        };
+        (client, state)
+    }
+
+    /// Creates a new client for a stream that is connected to a hub.
+    #[cfg(all(feature = "uds", unix))]
+    pub(crate) async fn new<S>(stream: S) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+    {
+        let (client, state) = Self::create(ConnectionState::Disconnected);
+        state.attach(stream).await;
+        client
+    }
+
+    /// Creates a new client for a stream that is connected to a hub, which re-establishes
+    /// the connection to the hub if it gets lost.
+    ///
+    /// All filters that have been registered with the hub are registered again once the
+    /// connection has been re-established. The connection is only reported as being
+    /// established again after all filters have been registered successfully. Otherwise,
+    /// the connection is dropped and re-established again.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The initial connection to the hub.
+    /// * `connect` - The function to invoke for re-establishing the connection.
+    /// * `policy` - The policy determining how long to wait between connection attempts.
+    #[cfg(feature = "tcp")]
+    pub(crate) async fn with_reconnect<S, F, Fut>(
+        stream: S,
+        connect: F,
+        policy: crate::tcp_transport::ReconnectPolicy,
+    ) -> Self
+    where
+        S: AsyncRead + AsyncWrite + Send + 'static,
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = io::Result<S>> + Send,
+    {
+        let (mut client, state) = Self::create(ConnectionState::Reconnecting);
+        let mut connection = state.attach(stream).await;
+        let supervisor_task = tokio::spawn(async move {
+            loop {
+                // wait for the connection to get lost
+                let _ = connection.await;
+                let mut delay = policy.initial_delay;
+                connection = loop {
+                    match connect().await {
+                        Ok(stream) => {
+                            let _registration = state.registration_lock.lock().await;
+                            let reader_task = state.start_reading(stream).await;
+                            match state.restore_hub_filters().await {
+                                Ok(()) => {
+                                    info!("re-established connection to hub");
+                                    state.connection.set_state(ConnectionState::Connected);
+                                    break reader_task;
+                                }
+                                Err(e) => {
+                                    info!("failed to re-register filters with hub: {e}");
+                                    state.drop_connection(reader_task).await;
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            debug!("failed to re-establish connection to hub: {e}");
+                        }
+                    }
+                    tokio::time::sleep(delay).await;
+                    delay = delay.saturating_mul(2).min(policy.max_delay);
+                };
+            }
+        });
+        client.tasks.push(supervisor_task);
+        client
+    }
+
+    /// Gets the state of the connection to the hub.
+    pub(crate) fn connection_state(&self) -> ConnectionState {
+        self.state.connection.state()
+    }
+
+    /// Gets the helper for informing listeners about changes of the connection state.
+    pub(crate) fn connection_state_notifier(&self) -> &ConnectionStateNotifier {
+        &self.state.connection
+    }
+
+    /// Sends a message to the hub.
+    pub(crate) async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        self.state.write(&Frame::Message(message)).await
+    }
+
+    /// Registers a listener for messages matching the given filters.
+    ///
+    /// The filters are registered with the hub, if no other listener has been registered
+    /// for the same filters yet.
+    pub(crate) async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let _registration = self.state.registration_lock.lock().await;
+        let listener = ComparableListener::new(listener);
+        let already_registered = self
+            .state
+            .listeners
+            .read()
+            .is_ok_and(|listeners| listeners.contains(source_filter, sink_filter, &listener));
+        if already_registered {
+            return Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "listener already registered for filters",
+            ));
+        }
+        let filters = (source_filter.to_owned(), sink_filter.cloned());
+        if !self.state.is_registered_with_hub(&filters) {
+            self.state.request(Frame::Register(filters.clone())).await?;
+            if let Ok(mut hub_filters) = self.state.hub_filters.lock() {
+                hub_filters.insert(filters);
+            }
+        }
+        let mut listeners = self.state.listeners.write().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })?;
+        listeners.insert(source_filter, sink_filter, listener);
+        Ok(())
+    }
+
+    /// Unregisters a listener.
+    ///
+    /// The filters are unregistered from the hub, if no other listener remains registered
+    /// for the same filters. If the hub fails to unregister the filters, the listener
+    /// remains registered.
+    pub(crate) async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let _registration = self.state.registration_lock.lock().await;
+        let listener = ComparableListener::new(listener);
+        let removed = self
+            .state
+            .listeners
+            .write()
+            .map(|mut listeners| {
+                listeners
+                    .remove(source_filter, sink_filter, &listener)
+                    .is_some()
+            })
+            .unwrap_or(false);
+        if !removed {
+            return Err(UStatus::fail_with_code(
+                UCode::NOT_FOUND,
+                "no such listener registered for filters",
+            ));
+        }
+        if !self.state.has_listeners_for(source_filter, sink_filter) {
+            let filters = (source_filter.to_owned(), sink_filter.cloned());
+            if let Err(e) = self.state.request(Frame::Unregister(filters.clone())).await {
+                if let Ok(mut listeners) = self.state.listeners.write() {
+                    listeners.insert(source_filter, sink_filter, listener);
+                }
+                return Err(e);
+            }
+            if let Ok(mut hub_filters) = self.state.hub_filters.lock() {
+                hub_filters.remove(&filters);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for HubClient {
+    fn drop(&mut self) {
+        self.tasks.iter().for_each(JoinHandle::abort);
+        if let Ok(mut reader_task) = self.state.reader_task.lock() {
+            if let Some(task) = reader_task.take() {
+                task.abort();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    use crate::{utransport::MockUListener, UMessageBuilder};
+
+    fn uri(value: &str) -> UUri {
+        UUri::try_from(value).expect("invalid URI")
+    }
+
+    #[test_case(Frame::Message(UMessageBuilder::publish(uri("//vehicle1/AA/1/8001")).build_with_payload("hello", crate::UPayloadFormat::UPAYLOAD_FORMAT_TEXT).unwrap()); "for message")]
+    #[test_case(Frame::Register((uri("//vehicle1/AA/1/FFFF"), None)); "for register without sink filter")]
+    #[test_case(Frame::Register((uri("//*/FFFF/FF/FFFF"), Some(uri("//vehicle1/BB/1/0")))); "for register with sink filter")]
+    #[test_case(Frame::Unregister((uri("//vehicle1/AA/1/FFFF"), Some(uri("//vehicle1/BB/1/0")))); "for unregister")]
+    #[test_case(Frame::Status(UStatus::fail_with_code(UCode::NOT_FOUND, "no such filter")); "for status")]
+    #[tokio::test]
+    async fn test_frame_survives_roundtrip(frame: Frame) {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        write_frame(&mut client, &frame).await.unwrap();
+        drop(client);
+        assert_eq!(read_frame(&mut server).await.unwrap(), Some(frame));
+        assert_eq!(read_frame(&mut server).await.unwrap(), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_register_listener_fails_if_hub_does_not_reply() {
+        let (client_stream, _hub_stream) = tokio::io::duplex(1024);
+        let (client, state) = HubClient::create(ConnectionState::Disconnected);
+        state.attach(client_stream).await;
+
+        let result = client
+            .register_listener(
+                &uri("//vehicle1/AA/1/FFFF"),
+                None,
+                Arc::new(MockUListener::new()),
+            )
+            .await;
+
+        assert!(result.is_err_and(|e| e.get_code() == UCode::DEADLINE_EXCEEDED));
+        assert!(!client
+            .state
+            .is_registered_with_hub(&(uri("//vehicle1/AA/1/FFFF"), None)));
+    }
+
+    #[tokio::test]
+    async fn test_listener_remains_registered_if_hub_fails_to_unregister_filters() {
+        let (client_stream, mut hub_stream) = tokio::io::duplex(1024);
+        let (client, state) = HubClient::create(ConnectionState::Disconnected);
+        state.attach(client_stream).await;
+        // a hub that accepts registrations but fails to unregister filters
+        tokio::spawn(async move {
+            while let Ok(Some(frame)) = read_frame(&mut hub_stream).await {
+                let status = match frame {
+                    Frame::Register(_) => UStatus::ok(),
+                    _ => UStatus::fail_with_code(UCode::INTERNAL, "failed to unregister"),
+                };
+                write_frame(&mut hub_stream, &Frame::Status(status))
+                    .await
+                    .unwrap();
+            }
+        });
+        let filters = (uri("//vehicle1/AA/1/FFFF"), None);
+        let listener: Arc<dyn UListener> = Arc::new(MockUListener::new());
+        client
+            .register_listener(&filters.0, None, listener.clone())
+            .await
+            .unwrap();
+
+        let result = client
+            .unregister_listener(&filters.0, None, listener.clone())
+            .await;
+
+        assert!(result.is_err_and(|e| e.get_code() == UCode::INTERNAL));
+        assert!(client.state.is_registered_with_hub(&filters));
+        assert!(client
+            .register_listener(&filters.0, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+    }
+
+    /// Replies to all register requests with the given code and records the requested filters.
+    #[cfg(feature = "tcp")]
+    fn fake_hub(
+        mut hub_stream: tokio::io::DuplexStream,
+        register_result: UCode,
+    ) -> JoinHandle<Vec<Filters>> {
+        tokio::spawn(async move {
+            let mut registered = vec![];
+            while let Ok(Some(frame)) = read_frame(&mut hub_stream).await {
+                if let Frame::Register(filters) = frame {
+                    registered.push(filters);
+                    let status = UStatus::fail_with_code(register_result, "register");
+                    if write_frame(&mut hub_stream, &Frame::Status(status))
+                        .await
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+            registered
+        })
+    }
+
+    #[cfg(feature = "tcp")]
+    #[tokio::test]
+    async fn test_connection_is_reestablished_if_filters_cannot_be_restored() {
+        let (connections_tx, connections_rx) = mpsc::unbounded_channel();
+        let connections_rx = Arc::new(tokio::sync::Mutex::new(connections_rx));
+        let connect = move || {
+            let connections_rx = connections_rx.clone();
+            async move {
+                connections_rx
+                    .lock()
+                    .await
+                    .recv()
+                    .await
+                    .ok_or_else(|| io::Error::other("no more connections"))
+            }
+        };
+        let policy = crate::tcp_transport::ReconnectPolicy {
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(10),
+        };
+
+        // GIVEN a client that has registered a listener with a hub
+        let (client_stream, hub_stream) = tokio::io::duplex(1024);
+        let first_hub = fake_hub(hub_stream, UCode::OK);
+        let client = HubClient::with_reconnect(client_stream, connect, policy).await;
+        let filters = (uri("//vehicle1/AA/1/FFFF"), None);
+        client
+            .register_listener(&filters.0, None, Arc::new(MockUListener::new()))
+            .await
+            .unwrap();
+
+        // WHEN the connection gets lost and the next hub fails to register the filters
+        let (client_stream, hub_stream) = tokio::io::duplex(1024);
+        let failing_hub = fake_hub(hub_stream, UCode::INTERNAL);
+        connections_tx.send(client_stream).unwrap();
+        first_hub.abort();
+
+        // THEN the client drops the connection without reporting it as being established
+        let registered = tokio::time::timeout(Duration::from_secs(2), failing_hub)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(registered, vec![filters.clone()]);
+        assert_eq!(client.connection_state(), ConnectionState::Reconnecting);
+
+        // and restores the filters with the hub that it connects to next
+        let (client_stream, hub_stream) = tokio::io::duplex(1024);
+        let (hub_stream_tx, hub_stream_rx) = oneshot::channel();
+        tokio::spawn(async move {
+            let mut hub_stream = hub_stream;
+            let frame = read_frame(&mut hub_stream).await.unwrap();
+            write_frame(&mut hub_stream, &Frame::Status(UStatus::ok()))
+                .await
+                .unwrap();
+            let _ = hub_stream_tx.send((frame, hub_stream));
+        });
+        connections_tx.send(client_stream).unwrap();
+        let (frame, _hub_stream) = tokio::time::timeout(Duration::from_secs(2), hub_stream_rx)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame, Some(Frame::Register(filters)));
+        let connected = tokio::time::timeout(Duration::from_secs(2), async {
+            while client.connection_state() != ConnectionState::Connected {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+        assert!(connected.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_read_frame_fails_for_invalid_data() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        client
+            .write_all(&[0x00, 0x00, 0x00, 0x02, 0xFF, 0x00])
+            .await
+            .unwrap();
+        assert!(read_frame(&mut server)
+            .await
+            .is_err_and(|e| e.kind() == io::ErrorKind::InvalidData));
+
+        client
+            .write_all(&(MAX_FRAME_SIZE as u32 + 1).to_be_bytes())
+            .await
+            .unwrap();
+        assert!(read_frame(&mut server)
+            .await
+            .is_err_and(|e| e.kind() == io::ErrorKind::InvalidData));
+    }
+}
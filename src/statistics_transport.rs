@@ -0,0 +1,270 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which collects statistics about the messages exchanged via another transport.
+
+The [`StatisticsCollectingTransport`] can be used for gaining insight into the message exchange of
+transports that do not collect [statistics](crate::UTransport::statistics) themselves.
+*/
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    middleware::{InterceptedTransport, StatisticsInterceptor},
+    ConnectionState, ConnectionStateListener, TransportCapabilities, TransportStatistics,
+    UListener, UMessage, UStatus, UTransport, UUri,
+};
+
+/// A [`UTransport`] that collects [`TransportStatistics`] about the messages exchanged via another transport.
+///
+/// Messages that have been sent successfully are counted as _sent_, messages that the wrapped transport
+/// has failed to send are counted as _dropped_. Messages are counted as _delivered_ whenever they are
+/// passed to a listener or returned from [`UTransport::receive`].
+///
+/// The statistics are collected by means of a [`StatisticsInterceptor`], so this transport behaves like an
+/// [`InterceptedTransport`] in all other respects.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use up_rust::{
+///     local_transport::LocalTransport, statistics_transport::StatisticsCollectingTransport,
+///     UMessageBuilder, UTransport, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = StatisticsCollectingTransport::new(Arc::new(LocalTransport::default()));
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// transport.send(UMessageBuilder::publish(topic).build().unwrap()).await.unwrap();
+/// assert_eq!(transport.statistics().unwrap().total_messages().sent, 1);
+/// # }
+/// ```
+pub struct StatisticsCollectingTransport {
+    transport: InterceptedTransport,
+    interceptor: Arc<StatisticsInterceptor>,
+}
+
+impl StatisticsCollectingTransport {
+    /// Creates a new transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The transport to delegate to.
+    pub fn new(inner: Arc<dyn UTransport>) -> Self {
+        let interceptor = Arc::new(StatisticsInterceptor::default());
+        StatisticsCollectingTransport {
+            transport: InterceptedTransport::new(inner, interceptor.clone()),
+            interceptor,
+        }
+    }
+
+    /// Resets the message counters and latencies collected so far.
+    pub fn reset_statistics(&self) {
+        self.interceptor.reset();
+    }
+}
+
+#[async_trait]
+impl UTransport for StatisticsCollectingTransport {
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        self.transport.send(message).await
+    }
+
+    async fn send_batch(&self, messages: Vec<UMessage>) -> Result<(), UStatus> {
+        self.transport.send_batch(messages).await
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        self.transport.capabilities()
+    }
+
+    fn statistics(&self) -> Option<TransportStatistics> {
+        self.transport.statistics()
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        self.transport.connection_state()
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.transport.register_connection_state_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.transport
+            .unregister_connection_state_listener(listener)
+    }
+
+    async fn receive(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+    ) -> Result<UMessage, UStatus> {
+        self.transport.receive(source_filter, sink_filter).await
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.transport
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.transport
+            .unregister_listener(source_filter, sink_filter, listener)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use crate::{
+        utransport::{BatchRecordingTransport, MockTransport, MockUListener},
+        UCode, UMessageBuilder, UMessageType, UPriority,
+    };
+
+    #[tokio::test]
+    async fn test_send_outcome_is_recorded() {
+        let mut inner = MockTransport::new();
+        inner.expect_do_send().once().returning(|_msg| Ok(()));
+        inner.expect_do_send().once().returning(|_msg| {
+            Err(UStatus::fail_with_code(
+                UCode::UNAVAILABLE,
+                "transport not available",
+            ))
+        });
+        let transport = StatisticsCollectingTransport::new(Arc::new(inner));
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+
+        for _ in 0..2 {
+            let _ = transport
+                .send(
+                    UMessageBuilder::publish(topic.clone())
+                        .with_priority(UPriority::UPRIORITY_CS2)
+                        .build()
+                        .unwrap(),
+                )
+                .await;
+        }
+
+        let counters = transport.statistics().unwrap().messages(
+            UMessageType::UMESSAGE_TYPE_PUBLISH,
+            UPriority::UPRIORITY_CS2,
+        );
+        assert_eq!(counters.sent, 1);
+        assert_eq!(counters.dropped, 1);
+
+        transport.reset_statistics();
+        assert_eq!(transport.statistics().unwrap().total_messages().sent, 0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_is_sent_using_inner_transport() {
+        let inner = Arc::new(BatchRecordingTransport::default());
+        let transport = StatisticsCollectingTransport::new(inner.clone());
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic).build().unwrap();
+
+        transport
+            .send_batch(vec![msg.clone(), msg.clone()])
+            .await
+            .unwrap();
+
+        assert_eq!(inner.batches(), vec![vec![msg.clone(), msg]]);
+        assert_eq!(transport.statistics().unwrap().total_messages().sent, 2);
+    }
+
+    #[tokio::test]
+    async fn test_listeners_and_deliveries_are_recorded() {
+        let captured: Arc<Mutex<Vec<Arc<dyn UListener>>>> = Arc::new(Mutex::new(vec![]));
+        let mut inner = MockTransport::new();
+        let captured_listeners = captured.clone();
+        inner.expect_do_register_listener().once().returning(
+            move |_source_filter, _sink_filter, listener| {
+                captured_listeners.lock().unwrap().push(listener);
+                Ok(())
+            },
+        );
+        inner.expect_do_register_listener().once().returning(
+            |_source_filter, _sink_filter, _listener| {
+                Err(UStatus::fail_with_code(
+                    UCode::ALREADY_EXISTS,
+                    "listener already registered",
+                ))
+            },
+        );
+        inner
+            .expect_do_unregister_listener()
+            .once()
+            .returning(|_source_filter, _sink_filter, _listener| Ok(()));
+        let transport = StatisticsCollectingTransport::new(Arc::new(inner));
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().once().return_const(());
+        let listener = Arc::new(listener);
+
+        transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+        assert!(transport
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+        assert_eq!(
+            transport.statistics().unwrap().listener_count(&topic, None),
+            1
+        );
+
+        // simulate the inner transport delivering a message
+        let recording_listener = captured.lock().unwrap()[0].clone();
+        recording_listener
+            .on_receive(UMessageBuilder::publish(topic.clone()).build().unwrap())
+            .await;
+        assert_eq!(
+            transport.statistics().unwrap().total_messages().delivered,
+            1
+        );
+
+        transport
+            .unregister_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        assert_eq!(transport.statistics().unwrap().total_listener_count(), 0);
+    }
+}
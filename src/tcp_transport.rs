@@ -0,0 +1,367 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which can be used for connecting uEntities by means of TCP connections.
+
+All uEntities connect to a [`TcpHub`]. The hub forwards messages to all connected [`TcpTransport`]s
+that have registered listeners with matching filters. Messages are exchanged as length-prefixed
+protobuf encoded `UMessage`s.
+
+A [`TcpTransport`] automatically re-establishes a lost connection to the hub and registers
+its listeners' filters again, as determined by its [`ReconnectPolicy`].
+*/
+
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tracing::info;
+
+use crate::{
+    socket_transport::{unavailable, Hub, HubClient, MAX_FRAME_SIZE},
+    ConnectionState, ConnectionStateListener, MessageOrdering, TransportCapabilities, UListener,
+    UMessage, UMessageType, UStatus, UTransport, UUri,
+};
+
+/// A hub that forwards messages between [`TcpTransport`]s.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use up_rust::tcp_transport::TcpHub;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let hub = TcpHub::bind("0.0.0.0:15000").await.unwrap();
+/// hub.run().await.unwrap();
+/// # }
+/// ```
+pub struct TcpHub {
+    listener: TcpListener,
+    hub: Arc<Hub>,
+}
+
+impl TcpHub {
+    /// Creates a new hub listening on a socket address.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the socket cannot be bound.
+    pub async fn bind<A: ToSocketAddrs>(addr: A) -> Result<Self, UStatus> {
+        let listener = TcpListener::bind(addr).await.map_err(unavailable)?;
+        Ok(TcpHub {
+            listener,
+            hub: Arc::new(Hub::default()),
+        })
+    }
+
+    /// Gets the socket address that this hub listens on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the address cannot be determined.
+    pub fn local_addr(&self) -> Result<SocketAddr, UStatus> {
+        self.listener.local_addr().map_err(unavailable)
+    }
+
+    /// Accepts connections from clients and forwards messages between them.
+    ///
+    /// This function runs until the hub's socket fails. All connections to clients
+    /// are closed when the hub is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if accepting connections fails.
+    pub async fn run(&self) -> Result<(), UStatus> {
+        loop {
+            let (stream, addr) = self.listener.accept().await.map_err(unavailable)?;
+            info!("accepted new client connection from {addr}");
+            let _ = stream.set_nodelay(true);
+            self.hub.serve(stream);
+        }
+    }
+}
+
+impl Drop for TcpHub {
+    fn drop(&mut self) {
+        self.hub.shutdown();
+    }
+}
+
+/// The policy that determines how a [`TcpTransport`] tries to re-establish a lost connection.
+///
+/// The transport waits for [`Self::initial_delay`] after a failed connection attempt. The delay
+/// is doubled after each subsequent failed attempt, up to [`Self::max_delay`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    /// The time to wait after the first failed connection attempt.
+    pub initial_delay: Duration,
+    /// The maximum time to wait between connection attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+/// A [`UTransport`] that exchanges messages with other uEntities via a [`TcpHub`].
+///
+/// Listener registrations are forwarded to the hub, which then forwards all messages
+/// matching the listeners' filters to this transport.
+///
+/// If the connection to the hub gets lost, the transport keeps trying to re-establish it in the
+/// background. Once reconnected, the filters of all registered listeners are registered with
+/// the hub again. While disconnected, sending messages and (un)registering listeners fails
+/// with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use up_rust::{tcp_transport::TcpTransport, UMessageBuilder, UTransport, UUri};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = TcpTransport::connect("127.0.0.1:15000").await.unwrap();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let msg = UMessageBuilder::publish(topic).build().unwrap();
+/// transport.send(msg).await.unwrap();
+/// # }
+/// ```
+pub struct TcpTransport {
+    client: HubClient,
+}
+
+impl TcpTransport {
+    /// Connects to a hub using the default [`ReconnectPolicy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the initial connection cannot be established.
+    pub async fn connect<A>(addr: A) -> Result<Self, UStatus>
+    where
+        A: ToSocketAddrs + Clone + Send + Sync + 'static,
+    {
+        Self::connect_with_policy(addr, ReconnectPolicy::default()).await
+    }
+
+    /// Connects to a hub.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address that the hub listens on.
+    /// * `policy` - The policy to apply when re-establishing a lost connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the initial connection cannot be established.
+    pub async fn connect_with_policy<A>(addr: A, policy: ReconnectPolicy) -> Result<Self, UStatus>
+    where
+        A: ToSocketAddrs + Clone + Send + Sync + 'static,
+    {
+        let stream = connect_stream(addr.clone()).await.map_err(unavailable)?;
+        let client =
+            HubClient::with_reconnect(stream, move || connect_stream(addr.clone()), policy).await;
+        Ok(TcpTransport { client })
+    }
+}
+
+async fn connect_stream<A: ToSocketAddrs>(addr: A) -> std::io::Result<TcpStream> {
+    let stream = TcpStream::connect(addr).await?;
+    stream.set_nodelay(true)?;
+    Ok(stream)
+}
+
+#[async_trait]
+impl UTransport for TcpTransport {
+    /// Sends a message to the hub.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the transport is currently
+    /// not connected to the hub.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        self.client.send(message).await
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities::default()
+            .with_push_delivery(true)
+            .with_pull_delivery(false)
+            .with_message_types(&[
+                UMessageType::UMESSAGE_TYPE_PUBLISH,
+                UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                UMessageType::UMESSAGE_TYPE_REQUEST,
+                UMessageType::UMESSAGE_TYPE_RESPONSE,
+            ])
+            .with_max_message_size(MAX_FRAME_SIZE)
+            .with_ordering(MessageOrdering::PerSender)
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        self.client.connection_state()
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .connection_state_notifier()
+            .add_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .connection_state_notifier()
+            .remove_listener(listener)
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .unregister_listener(source_filter, sink_filter, listener)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        utransport::forwarding_listener, LocalUriProvider, StaticUriProvider, UCode,
+        UMessageBuilder,
+    };
+
+    const FAST_RECONNECT: ReconnectPolicy = ReconnectPolicy {
+        initial_delay: Duration::from_millis(10),
+        max_delay: Duration::from_millis(50),
+    };
+
+    async fn start_hub(addr: SocketAddr) -> (SocketAddr, tokio::task::JoinHandle<()>) {
+        let hub = TcpHub::bind(addr).await.unwrap();
+        let local_addr = hub.local_addr().unwrap();
+        let task = tokio::spawn(async move {
+            let _ = hub.run().await;
+        });
+        (local_addr, task)
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_forwarded_to_matching_listeners() {
+        let (addr, _hub) = start_hub("127.0.0.1:0".parse().unwrap()).await;
+        let subscriber = TcpTransport::connect(addr).await.unwrap();
+        let publisher = TcpTransport::connect(addr).await.unwrap();
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+
+        let (listener, mut rx) = forwarding_listener();
+        let topic = uri_provider.get_resource_uri(0x9000);
+        subscriber
+            .register_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+
+        let msg = UMessageBuilder::publish(topic.clone()).build().unwrap();
+        publisher.send(msg.clone()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        assert_eq!(received.unwrap(), Some(msg));
+
+        subscriber
+            .unregister_listener(&topic, None, listener.clone())
+            .await
+            .unwrap();
+        assert!(subscriber
+            .unregister_listener(&topic, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_transport_reconnects_and_restores_registrations() {
+        // GIVEN a transport that has registered a listener with a hub
+        let (addr, hub_task) = start_hub("127.0.0.1:0".parse().unwrap()).await;
+        let subscriber = TcpTransport::connect_with_policy(addr, FAST_RECONNECT)
+            .await
+            .unwrap();
+        let (listener, mut rx) = forwarding_listener();
+        let topic = UUri::try_from_parts("my-vehicle", 0x100d, 0x02, 0x9000).unwrap();
+        subscriber
+            .register_listener(&topic, None, listener)
+            .await
+            .unwrap();
+
+        // WHEN the hub is restarted
+        hub_task.abort();
+        let _ = hub_task.await;
+        let msg = UMessageBuilder::publish(topic.clone()).build().unwrap();
+        let result = tokio::time::timeout(Duration::from_secs(2), async {
+            while subscriber.send(msg.clone()).await.is_ok() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+        assert!(result.is_ok(), "transport did not detect lost connection");
+        assert_eq!(subscriber.connection_state(), ConnectionState::Reconnecting);
+        let (_addr, _new_hub) = start_hub(addr).await;
+
+        // THEN the transport reconnects and receives messages for its listener again
+        let publisher = TcpTransport::connect(addr).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                publisher.send(msg.clone()).await.unwrap();
+                if let Ok(received) =
+                    tokio::time::timeout(Duration::from_millis(50), rx.recv()).await
+                {
+                    return received;
+                }
+            }
+        })
+        .await;
+        assert_eq!(received.unwrap(), Some(msg));
+        assert_eq!(subscriber.connection_state(), ConnectionState::Connected);
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_for_unreachable_hub() {
+        let (addr, hub_task) = start_hub("127.0.0.1:0".parse().unwrap()).await;
+        hub_task.abort();
+        let _ = hub_task.await;
+        assert!(TcpTransport::connect(addr)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::UNAVAILABLE));
+    }
+}
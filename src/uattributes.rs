@@ -138,6 +138,9 @@ impl UAttributes {
 
     /// Checks if the message that is described by these attributes should be considered expired.
     ///
+    /// The current time is taken from the same clock that is used by [`UUID::build`], i.e. from a
+    /// simulation's virtual clock if the check is performed on the thread that has started the simulation.
+    ///
     /// # Errors
     ///
     /// Returns an error if [`Self::ttl`] (time-to-live) contains a value greater than 0, but
@@ -152,7 +155,7 @@ impl UAttributes {
 
         // [impl->dsn~up-attributes-ttl-timeout~1]
         if let Some(creation_time) = self.id.as_ref().and_then(UUID::get_time) {
-            let delta = match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+            let delta = match crate::clock::now().duration_since(SystemTime::UNIX_EPOCH) {
                 Ok(duration) => {
                     if let Ok(duration) = u64::try_from(duration.as_millis()) {
                         duration - creation_time
@@ -0,0 +1,542 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which can be used for distributing Publish messages by means of UDP multicast.
+
+Each topic is mapped to a multicast group and port by means of a [`MulticastGroupMap`].
+Messages are sent as protobuf encoded `UMessage`s, one message per datagram.
+
+UDP does not guarantee delivery of datagrams. The transport therefore only supports Publish messages,
+for which the loss of an occasional message is usually acceptable, e.g. high-rate telemetry data.
+*/
+
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+    sync::{Arc, Mutex, RwLock},
+};
+
+use async_trait::async_trait;
+use protobuf::Message;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::{net::UdpSocket, task::JoinHandle};
+use tracing::{debug, info};
+
+use crate::{
+    utransport::FilterIndex, verify_filter_criteria, ComparableListener, PublishValidator,
+    TransportCapabilities, UAttributesValidator, UCode, UListener, UMessage, UMessageType, UStatus,
+    UTransport, UUri,
+};
+
+/// The maximum size of a UDP datagram's payload.
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+/// A mapping of topics to the multicast groups that messages published to the topics are sent to.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::net::{Ipv4Addr, SocketAddrV4};
+/// use up_rust::{udp_transport::MulticastGroupMap, UUri};
+///
+/// let default_group = SocketAddrV4::new(Ipv4Addr::new(239, 255, 0, 1), 30_000);
+/// let telemetry_group = SocketAddrV4::new(Ipv4Addr::new(239, 255, 0, 2), 30_001);
+/// let telemetry_topics = UUri::try_from("//*/A100/1/FFFF").unwrap();
+/// let groups = MulticastGroupMap::new(default_group).with_group(&telemetry_topics, telemetry_group);
+///
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// assert_eq!(groups.group_for_topic(&topic), telemetry_group);
+/// let other_topic = UUri::try_from("//my-vehicle/B200/1/8001").unwrap();
+/// assert_eq!(groups.group_for_topic(&other_topic), default_group);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MulticastGroupMap {
+    default_group: SocketAddrV4,
+    groups: Vec<(UUri, SocketAddrV4)>,
+}
+
+impl MulticastGroupMap {
+    /// Creates a new mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `default_group` - The multicast group to use for topics that do not match any
+    ///   of the patterns added using [`Self::with_group`].
+    pub fn new(default_group: SocketAddrV4) -> Self {
+        MulticastGroupMap {
+            default_group,
+            groups: vec![],
+        }
+    }
+
+    /// Adds a multicast group to use for topics matching a given pattern.
+    ///
+    /// Patterns are evaluated in the order in which they have been added.
+    pub fn with_group(mut self, topic_pattern: &UUri, group: SocketAddrV4) -> Self {
+        self.groups.push((topic_pattern.to_owned(), group));
+        self
+    }
+
+    /// Gets the multicast group that messages published to a topic are sent to.
+    pub fn group_for_topic(&self, topic: &UUri) -> SocketAddrV4 {
+        self.groups
+            .iter()
+            .find(|(pattern, _group)| pattern.matches(topic))
+            .map_or(self.default_group, |(_pattern, group)| *group)
+    }
+
+    /// Gets all multicast groups contained in this mapping.
+    fn all_groups(&self) -> HashSet<SocketAddrV4> {
+        self.groups
+            .iter()
+            .map(|(_pattern, group)| *group)
+            .chain(std::iter::once(self.default_group))
+            .collect()
+    }
+}
+
+/// Options for the sockets used by a [`UdpMulticastTransport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MulticastOptions {
+    /// The address of the local network interface to send datagrams from and join multicast groups on.
+    /// [`Ipv4Addr::UNSPECIFIED`] lets the operating system choose the interface.
+    pub interface: Ipv4Addr,
+    /// The maximum number of hops that datagrams may be forwarded across.
+    pub ttl: u32,
+    /// Indicates whether datagrams are also delivered to receivers on the sending host.
+    pub loopback: bool,
+}
+
+impl Default for MulticastOptions {
+    fn default() -> Self {
+        MulticastOptions {
+            interface: Ipv4Addr::UNSPECIFIED,
+            ttl: 1,
+            loopback: true,
+        }
+    }
+}
+
+/// Creates a socket that has joined the given multicast groups, all of which use the same port.
+///
+/// The socket allows other sockets on the same host to bind to the same port, so that multiple
+/// uEntities on the same host can receive messages from the same groups.
+fn bind_receiver(
+    port: u16,
+    groups: &[Ipv4Addr],
+    options: &MulticastOptions,
+) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)).into())?;
+    for group in groups {
+        socket.join_multicast_v4(group, &options.interface)?;
+    }
+    UdpSocket::from_std(socket.into())
+}
+
+/// Creates a socket for sending datagrams to multicast groups.
+fn bind_sender(options: &MulticastOptions) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_nonblocking(true)?;
+    socket.set_multicast_if_v4(&options.interface)?;
+    socket.set_multicast_ttl_v4(options.ttl)?;
+    socket.set_multicast_loop_v4(options.loopback)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)).into())?;
+    UdpSocket::from_std(socket.into())
+}
+
+fn unavailable(err: io::Error) -> UStatus {
+    UStatus::fail_with_code(UCode::UNAVAILABLE, err.to_string())
+}
+
+type Listeners = Arc<RwLock<FilterIndex<ComparableListener>>>;
+
+/// Receives datagrams from a socket and dispatches the contained messages to matching listeners.
+async fn receive_messages(socket: UdpSocket, listeners: Listeners) {
+    let mut buf = vec![0_u8; MAX_DATAGRAM_SIZE];
+    loop {
+        let (len, sender) = match socket.recv_from(&mut buf).await {
+            Ok(received) => received,
+            Err(e) => {
+                info!("stopping to receive datagrams: {e}");
+                return;
+            }
+        };
+        let msg = match UMessage::parse_from_bytes(&buf[..len]) {
+            Ok(msg) if msg.is_publish() => msg,
+            Ok(_msg) => {
+                debug!(%sender, "ignoring non-publish message");
+                continue;
+            }
+            Err(e) => {
+                debug!(%sender, "ignoring invalid datagram: {e}");
+                continue;
+            }
+        };
+        // collect the matching listeners first so that the lock is not being held
+        // while the listeners process the message
+        let matching_listeners: Vec<ComparableListener> = listeners
+            .read()
+            .map(|listeners| {
+                listeners
+                    .find_matches_for_message(&msg)
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        for listener in matching_listeners {
+            listener.on_receive(msg.clone()).await;
+        }
+    }
+}
+
+/// A [`UTransport`] that sends Publish messages to UDP multicast groups.
+///
+/// Each message is sent to the group that the message's topic is mapped to by the transport's
+/// [`MulticastGroupMap`]. Once the first listener has been registered, the transport joins all
+/// groups contained in the mapping and dispatches all received Publish messages to the listeners
+/// with matching source filters.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::net::{Ipv4Addr, SocketAddrV4};
+/// use up_rust::{
+///     udp_transport::{MulticastGroupMap, UdpMulticastTransport},
+///     UMessageBuilder, UTransport, UUri,
+/// };
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let groups = MulticastGroupMap::new(SocketAddrV4::new(Ipv4Addr::new(239, 255, 0, 1), 30_000));
+/// let transport = UdpMulticastTransport::new(groups).await.unwrap();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let msg = UMessageBuilder::publish(topic).build().unwrap();
+/// transport.send(msg).await.unwrap();
+/// # }
+/// ```
+pub struct UdpMulticastTransport {
+    groups: MulticastGroupMap,
+    options: MulticastOptions,
+    socket: UdpSocket,
+    listeners: Listeners,
+    receiver_tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl UdpMulticastTransport {
+    /// Creates a new transport using the default [`MulticastOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the mapping contains a non-multicast address,
+    /// * [`UCode::UNAVAILABLE`] if the socket for sending messages cannot be created.
+    pub async fn new(groups: MulticastGroupMap) -> Result<Self, UStatus> {
+        Self::new_with_options(groups, MulticastOptions::default()).await
+    }
+
+    /// Creates a new transport.
+    ///
+    /// # Arguments
+    ///
+    /// * `groups` - The mapping of topics to multicast groups.
+    /// * `options` - The options to apply to the transport's sockets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the mapping contains a non-multicast address,
+    /// * [`UCode::UNAVAILABLE`] if the socket for sending messages cannot be created.
+    pub async fn new_with_options(
+        groups: MulticastGroupMap,
+        options: MulticastOptions,
+    ) -> Result<Self, UStatus> {
+        if let Some(group) = groups.all_groups().iter().find(|g| !g.ip().is_multicast()) {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                format!("[{group}] is not a multicast address"),
+            ));
+        }
+        let socket = bind_sender(&options).map_err(unavailable)?;
+        Ok(UdpMulticastTransport {
+            groups,
+            options,
+            socket,
+            listeners: Arc::new(RwLock::new(FilterIndex::default())),
+            receiver_tasks: Mutex::new(vec![]),
+        })
+    }
+
+    /// Joins all multicast groups, unless this has already been done.
+    fn start_receivers(&self) -> Result<(), UStatus> {
+        let mut receiver_tasks = self.receiver_tasks.lock().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for receivers")
+        })?;
+        if !receiver_tasks.is_empty() {
+            return Ok(());
+        }
+        let mut groups_by_port: HashMap<u16, Vec<Ipv4Addr>> = HashMap::new();
+        for group in self.groups.all_groups() {
+            groups_by_port
+                .entry(group.port())
+                .or_default()
+                .push(*group.ip());
+        }
+        let sockets = groups_by_port
+            .iter()
+            .map(|(port, groups)| bind_receiver(*port, groups, &self.options))
+            .collect::<io::Result<Vec<UdpSocket>>>()
+            .map_err(unavailable)?;
+        receiver_tasks.extend(
+            sockets
+                .into_iter()
+                .map(|socket| tokio::spawn(receive_messages(socket, self.listeners.clone()))),
+        );
+        Ok(())
+    }
+}
+
+impl Drop for UdpMulticastTransport {
+    fn drop(&mut self) {
+        if let Ok(receiver_tasks) = self.receiver_tasks.get_mut() {
+            receiver_tasks.iter().for_each(JoinHandle::abort);
+        }
+    }
+}
+
+#[async_trait]
+impl UTransport for UdpMulticastTransport {
+    /// Sends a Publish message to the multicast group that the message's topic is mapped to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::UNIMPLEMENTED`] if the message is not a Publish message,
+    /// * [`UCode::INVALID_ARGUMENT`] if the message's attributes are invalid or if the message
+    ///   does not fit into a single datagram,
+    /// * [`UCode::UNAVAILABLE`] if the datagram cannot be sent.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        if !message.is_publish() {
+            return Err(UStatus::fail_with_code(
+                UCode::UNIMPLEMENTED,
+                "transport supports Publish messages only",
+            ));
+        }
+        let Some(topic) = message.source() else {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "message has no source",
+            ));
+        };
+        if let Some(attributes) = message.attributes.as_ref() {
+            PublishValidator
+                .validate(attributes)
+                .map_err(|e| UStatus::fail_with_code(UCode::INVALID_ARGUMENT, e.to_string()))?;
+        }
+        let group = self.groups.group_for_topic(topic);
+        let bytes = message
+            .write_to_bytes()
+            .map_err(|e| UStatus::fail_with_code(UCode::INVALID_ARGUMENT, e.to_string()))?;
+        if bytes.len() > MAX_DATAGRAM_SIZE {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "message exceeds maximum datagram size",
+            ));
+        }
+        self.socket
+            .send_to(&bytes, group)
+            .await
+            .map(|_len| ())
+            .map_err(unavailable)
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities::default()
+            .with_push_delivery(true)
+            .with_pull_delivery(false)
+            .with_message_types(&[UMessageType::UMESSAGE_TYPE_PUBLISH])
+            .with_max_message_size(MAX_DATAGRAM_SIZE)
+    }
+
+    /// Registers a listener for Publish messages.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with
+    /// * [`UCode::INVALID_ARGUMENT`] if the filters cannot be used for registering a listener
+    ///   or if a sink filter is given, which would never match any Publish message,
+    /// * [`UCode::ALREADY_EXISTS`] if the listener has already been registered for the filters,
+    /// * [`UCode::UNAVAILABLE`] if the multicast groups cannot be joined.
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        verify_filter_criteria(source_filter, sink_filter)?;
+        if sink_filter.is_some() {
+            return Err(UStatus::fail_with_code(
+                UCode::INVALID_ARGUMENT,
+                "transport supports Publish messages only, which do not have a sink",
+            ));
+        }
+        self.start_receivers()?;
+        let mut listeners = self.listeners.write().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })?;
+        if listeners.insert(source_filter, None, ComparableListener::new(listener)) {
+            Ok(())
+        } else {
+            Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "listener already registered for filters",
+            ))
+        }
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        let mut listeners = self.listeners.write().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })?;
+        listeners
+            .remove(
+                source_filter,
+                sink_filter,
+                &ComparableListener::new(listener),
+            )
+            .map(|_listener| ())
+            .ok_or_else(|| {
+                UStatus::fail_with_code(UCode::NOT_FOUND, "no such listener registered for filters")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use crate::{utransport::forwarding_listener, UMessageBuilder};
+
+    const LOOPBACK: MulticastOptions = MulticastOptions {
+        interface: Ipv4Addr::LOCALHOST,
+        ttl: 0,
+        loopback: true,
+    };
+
+    fn group(last_octet: u8, port: u16) -> SocketAddrV4 {
+        SocketAddrV4::new(Ipv4Addr::new(239, 255, 77, last_octet), port)
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_delivered_to_matching_listeners() {
+        let groups = MulticastGroupMap::new(group(1, 47_101)).with_group(
+            &UUri::try_from("//*/A100/1/FFFF").unwrap(),
+            group(2, 47_102),
+        );
+        let subscriber = UdpMulticastTransport::new_with_options(groups.clone(), LOOPBACK)
+            .await
+            .unwrap();
+        let publisher = UdpMulticastTransport::new_with_options(groups, LOOPBACK)
+            .await
+            .unwrap();
+
+        let (listener, mut rx) = forwarding_listener();
+        let topic_filter = UUri::try_from("//my-vehicle/A100/1/FFFF").unwrap();
+        subscriber
+            .register_listener(&topic_filter, None, listener.clone())
+            .await
+            .unwrap();
+
+        let other_topic = UUri::try_from("//my-vehicle/B200/1/8001").unwrap();
+        let ignored = UMessageBuilder::publish(other_topic).build().unwrap();
+        publisher.send(ignored).await.unwrap();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let msg = UMessageBuilder::publish(topic).build().unwrap();
+        publisher.send(msg.clone()).await.unwrap();
+
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        assert_eq!(received.unwrap(), Some(msg));
+        assert!(rx.try_recv().is_err());
+
+        subscriber
+            .unregister_listener(&topic_filter, None, listener.clone())
+            .await
+            .unwrap();
+        assert!(subscriber
+            .unregister_listener(&topic_filter, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_non_publish_messages() {
+        let transport = UdpMulticastTransport::new_with_options(
+            MulticastGroupMap::new(group(3, 47_103)),
+            LOOPBACK,
+        )
+        .await
+        .unwrap();
+        let method = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+        let reply_to = UUri::try_from("//my-vehicle/B200/1/0").unwrap();
+        let request = UMessageBuilder::request(method, reply_to, 5000)
+            .build()
+            .unwrap();
+        let response = UMessageBuilder::response_for_request(request.attributes.as_ref().unwrap())
+            .build()
+            .unwrap();
+
+        for msg in [request, response] {
+            assert!(transport
+                .send(msg)
+                .await
+                .is_err_and(|e| e.get_code() == UCode::UNIMPLEMENTED));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_register_listener_rejects_sink_filter() {
+        let transport = UdpMulticastTransport::new_with_options(
+            MulticastGroupMap::new(group(4, 47_104)),
+            LOOPBACK,
+        )
+        .await
+        .unwrap();
+        let (listener, _rx) = forwarding_listener();
+        let source_filter = UUri::try_from("//*/FFFF/FF/FFFF").unwrap();
+        let sink_filter = UUri::try_from("//my-vehicle/B200/1/0").unwrap();
+        assert!(transport
+            .register_listener(&source_filter, Some(&sink_filter), listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+
+    #[tokio::test]
+    async fn test_new_fails_for_non_multicast_address() {
+        let groups = MulticastGroupMap::new(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 47_105));
+        assert!(UdpMulticastTransport::new(groups)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::INVALID_ARGUMENT));
+    }
+}
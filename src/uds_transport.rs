@@ -0,0 +1,322 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+/*!
+Provides a UTransport which can be used for connecting uEntities running in different processes
+on the same host by means of Unix domain sockets.
+
+All uEntities connect to a [`UdsBroker`] which listens on a socket file. The broker forwards
+messages to all connected [`UdsTransport`]s that have registered listeners with matching filters.
+Messages are exchanged as length-delimited protobuf encoded `UMessage`s.
+*/
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use tokio::net::{UnixListener, UnixStream};
+use tracing::{debug, info};
+
+use crate::{
+    socket_transport::{unavailable, Hub, HubClient, MAX_FRAME_SIZE},
+    ConnectionState, ConnectionStateListener, MessageOrdering, TransportCapabilities, UListener,
+    UMessage, UMessageType, UStatus, UTransport, UUri,
+};
+
+/// A broker that forwards messages between [`UdsTransport`]s running in different processes.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use up_rust::uds_transport::UdsBroker;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let broker = UdsBroker::bind("/run/uprotocol/broker.sock").unwrap();
+/// broker.run().await.unwrap();
+/// # }
+/// ```
+pub struct UdsBroker {
+    listener: UnixListener,
+    path: PathBuf,
+    hub: Arc<Hub>,
+}
+
+impl UdsBroker {
+    /// Creates a new broker listening on a socket file.
+    ///
+    /// A stale socket file left behind by a previous broker instance is replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the socket cannot be bound.
+    pub fn bind<P: AsRef<Path>>(path: P) -> Result<Self, UStatus> {
+        let path = path.as_ref().to_path_buf();
+        if path.exists() && std::os::unix::net::UnixStream::connect(&path).is_err() {
+            debug!("removing stale socket file {}", path.display());
+            std::fs::remove_file(&path).map_err(unavailable)?;
+        }
+        let listener = UnixListener::bind(&path).map_err(unavailable)?;
+        Ok(UdsBroker {
+            listener,
+            path,
+            hub: Arc::new(Hub::default()),
+        })
+    }
+
+    /// Gets the path of the socket file that this broker listens on.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Accepts connections from clients and forwards messages between them.
+    ///
+    /// This function runs until the broker's socket fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if accepting connections fails.
+    pub async fn run(&self) -> Result<(), UStatus> {
+        loop {
+            let (stream, _addr) = self.listener.accept().await.map_err(unavailable)?;
+            info!("accepted new client connection");
+            self.hub.serve(stream);
+        }
+    }
+}
+
+impl Drop for UdsBroker {
+    fn drop(&mut self) {
+        self.hub.shutdown();
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// A [`UTransport`] that exchanges messages with other processes via a [`UdsBroker`].
+///
+/// Listener registrations are forwarded to the broker, which then forwards all messages
+/// matching the listeners' filters to this transport.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use up_rust::{uds_transport::UdsTransport, UMessageBuilder, UTransport, UUri};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = UdsTransport::connect("/run/uprotocol/broker.sock").await.unwrap();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let msg = UMessageBuilder::publish(topic).build().unwrap();
+/// transport.send(msg).await.unwrap();
+/// # }
+/// ```
+pub struct UdsTransport {
+    client: HubClient,
+}
+
+impl UdsTransport {
+    /// Connects to a broker.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The socket file that the broker listens on.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the connection cannot be established.
+    pub async fn connect<P: AsRef<Path>>(path: P) -> Result<Self, UStatus> {
+        let stream = UnixStream::connect(path).await.map_err(unavailable)?;
+        Ok(UdsTransport {
+            client: HubClient::new(stream).await,
+        })
+    }
+}
+
+#[async_trait]
+impl UTransport for UdsTransport {
+    /// Sends a message to the broker.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::UNAVAILABLE`](crate::UCode::UNAVAILABLE) if the connection to the broker has been lost.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        self.client.send(message).await
+    }
+
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities::default()
+            .with_push_delivery(true)
+            .with_pull_delivery(false)
+            .with_message_types(&[
+                UMessageType::UMESSAGE_TYPE_PUBLISH,
+                UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                UMessageType::UMESSAGE_TYPE_REQUEST,
+                UMessageType::UMESSAGE_TYPE_RESPONSE,
+            ])
+            .with_max_message_size(MAX_FRAME_SIZE)
+            .with_ordering(MessageOrdering::PerSender)
+    }
+
+    fn connection_state(&self) -> ConnectionState {
+        self.client.connection_state()
+    }
+
+    fn register_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .connection_state_notifier()
+            .add_listener(listener)
+    }
+
+    fn unregister_connection_state_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .connection_state_notifier()
+            .remove_listener(listener)
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .register_listener(source_filter, sink_filter, listener)
+            .await
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.client
+            .unregister_listener(source_filter, sink_filter, listener)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use crate::{
+        utransport::{forwarding_listener, MockUListener},
+        LocalUriProvider, StaticUriProvider, UCode, UMessageBuilder,
+    };
+
+    fn start_broker(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("up-rust-{}-{name}.sock", std::process::id()));
+        let broker = UdsBroker::bind(&path).unwrap();
+        tokio::spawn(async move { broker.run().await });
+        path
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_forwarded_to_matching_listeners() {
+        // GIVEN a broker with two connected transports
+        let path = start_broker("forward");
+        let subscriber = UdsTransport::connect(&path).await.unwrap();
+        let publisher = UdsTransport::connect(&path).await.unwrap();
+        let uri_provider = StaticUriProvider::new("my-vehicle", 0x100d, 0x02);
+
+        // and a listener registered for a topic
+        let (listener, mut rx) = forwarding_listener();
+        let topic_filter = UUri::try_from_parts("my-vehicle", 0x100d, 0x02, 0xFFFF).unwrap();
+        subscriber
+            .register_listener(&topic_filter, None, listener.clone())
+            .await
+            .unwrap();
+        assert!(subscriber
+            .register_listener(&topic_filter, None, listener.clone())
+            .await
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+
+        // WHEN the other transport publishes a matching and a non-matching message
+        let other_provider = StaticUriProvider::new("my-vehicle", 0x200d, 0x02);
+        publisher
+            .send(
+                UMessageBuilder::publish(other_provider.get_resource_uri(0x9000))
+                    .build()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let msg = UMessageBuilder::publish(uri_provider.get_resource_uri(0x9000))
+            .build()
+            .unwrap();
+        publisher.send(msg.clone()).await.unwrap();
+
+        // THEN only the matching message is delivered to the listener
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        assert_eq!(received.unwrap(), Some(msg.clone()));
+
+        // and no more messages are delivered once the listener has been unregistered
+        subscriber
+            .unregister_listener(&topic_filter, None, listener.clone())
+            .await
+            .unwrap();
+        publisher.send(msg).await.unwrap();
+        assert!(tokio::time::timeout(Duration::from_millis(200), rx.recv())
+            .await
+            .is_err());
+        assert!(subscriber
+            .unregister_listener(&topic_filter, None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_filters_are_removed_when_client_disconnects() {
+        let path = start_broker("disconnect");
+        let topic = UUri::try_from_parts("my-vehicle", 0x100d, 0x02, 0x9000).unwrap();
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().never();
+        let subscriber = UdsTransport::connect(&path).await.unwrap();
+        subscriber
+            .register_listener(&topic, None, Arc::new(listener))
+            .await
+            .unwrap();
+        drop(subscriber);
+
+        // the broker keeps working for the remaining clients
+        let (listener, mut rx) = forwarding_listener();
+        let transport = UdsTransport::connect(&path).await.unwrap();
+        transport
+            .register_listener(&topic, None, listener)
+            .await
+            .unwrap();
+        let msg = UMessageBuilder::publish(topic).build().unwrap();
+        transport.send(msg.clone()).await.unwrap();
+        let received = tokio::time::timeout(Duration::from_secs(2), rx.recv()).await;
+        assert_eq!(received.unwrap(), Some(msg));
+    }
+
+    #[tokio::test]
+    async fn test_connect_fails_for_missing_broker() {
+        let path = std::env::temp_dir().join("up-rust-non-existing-broker.sock");
+        assert!(UdsTransport::connect(path)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::UNAVAILABLE));
+    }
+}
@@ -11,6 +11,17 @@
  * SPDX-License-Identifier: Apache-2.0
  ********************************************************************************/
 
+mod capabilities;
+mod connection_state;
+mod filter_index;
+#[cfg(feature = "util")]
+mod message_stream;
+mod priority_scheduler;
+#[cfg(any(test, feature = "test-util"))]
+mod recording_transport;
+mod statistics;
+mod uri_provider_config;
+
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::num::TryFromIntError;
@@ -21,6 +32,19 @@ use async_trait::async_trait;
 
 use crate::{UCode, UMessage, UStatus, UUri};
 
+pub use capabilities::{MessageOrdering, TransportCapabilities};
+#[cfg(feature = "test-util")]
+pub use connection_state::MockConnectionStateListener;
+pub use connection_state::{ConnectionState, ConnectionStateListener, ConnectionStateNotifier};
+pub use filter_index::FilterIndex;
+#[cfg(feature = "util")]
+pub use message_stream::MessageStream;
+pub use priority_scheduler::PriorityScheduler;
+#[cfg(any(test, feature = "test-util"))]
+pub use recording_transport::RecordingTransport;
+pub use statistics::{LatencyStatistics, MessageCounters, StatisticsRecorder, TransportStatistics};
+pub use uri_provider_config::UriProviderConfigError;
+
 /// Verifies that given UUris can be used as source and sink filter UUris
 /// for registering listeners.
 ///
@@ -229,6 +253,89 @@ pub trait UTransport: Send + Sync {
     /// Returns an error if the message could not be sent.
     async fn send(&self, message: UMessage) -> Result<(), UStatus>;
 
+    /// Sends multiple messages using this transport's message exchange mechanism.
+    ///
+    /// Transports may override this function in order to send a batch of messages more efficiently
+    /// than by means of sending each message individually.
+    ///
+    /// This default implementation invokes [`UTransport::send`] for each message, in the given order.
+    ///
+    /// # Arguments
+    ///
+    /// * `messages` - The messages to send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the messages could not be sent. In this case, the messages preceding
+    /// the failed message have been sent, while the remaining messages have not been sent.
+    async fn send_batch(&self, messages: Vec<UMessage>) -> Result<(), UStatus> {
+        for message in messages {
+            self.send(message).await?;
+        }
+        Ok(())
+    }
+
+    /// Gets a description of the features supported by this transport.
+    ///
+    /// Clients can use this information for detecting missing features up front instead of
+    /// running into [`UCode::UNIMPLEMENTED`] errors at runtime.
+    ///
+    /// This default implementation returns the [default](TransportCapabilities::default) descriptor,
+    /// which does not make any statement about the supported features.
+    fn capabilities(&self) -> TransportCapabilities {
+        TransportCapabilities::default()
+    }
+
+    /// Gets a snapshot of the statistics that this transport has collected about the messages it has
+    /// processed and the listeners that are registered with it.
+    ///
+    /// This default implementation returns `None`, indicating that the transport does not collect any
+    /// statistics.
+    fn statistics(&self) -> Option<TransportStatistics> {
+        None
+    }
+
+    /// Gets the current state of this transport's connection to the underlying network or broker.
+    ///
+    /// This default implementation returns [`ConnectionState::Connected`].
+    fn connection_state(&self) -> ConnectionState {
+        ConnectionState::Connected
+    }
+
+    /// Registers a listener to be informed about changes of this transport's [connection state](Self::connection_state).
+    ///
+    /// This default implementation returns an error with [`UCode::UNIMPLEMENTED`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener could not be registered.
+    fn register_connection_state_listener(
+        &self,
+        _listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        Err(UStatus::fail_with_code(
+            UCode::UNIMPLEMENTED,
+            "not implemented",
+        ))
+    }
+
+    /// Deregisters a listener for changes of this transport's connection state.
+    ///
+    /// This default implementation returns an error with [`UCode::UNIMPLEMENTED`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener could not be unregistered, for example if the given listener does not exist.
+    fn unregister_connection_state_listener(
+        &self,
+        _listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        Err(UStatus::fail_with_code(
+            UCode::UNIMPLEMENTED,
+            "not implemented",
+        ))
+    }
+
     /// Receives a message from the transport.
     ///
     /// This default implementation returns an error with [`UCode::UNIMPLEMENTED`].
@@ -353,6 +460,78 @@ impl UTransport for MockTransport {
     }
 }
 
+/// A listener that forwards all received messages to a channel.
+#[cfg(any(test, feature = "test-util"))]
+struct ForwardingListener(tokio::sync::mpsc::UnboundedSender<UMessage>);
+
+#[cfg(any(test, feature = "test-util"))]
+#[async_trait]
+impl UListener for ForwardingListener {
+    async fn on_receive(&self, msg: UMessage) {
+        let _ = self.0.send(msg);
+    }
+}
+
+/// Creates a listener that forwards all received messages to the returned receiver.
+#[cfg(any(test, feature = "test-util"))]
+pub(crate) fn forwarding_listener() -> (
+    Arc<dyn UListener>,
+    tokio::sync::mpsc::UnboundedReceiver<UMessage>,
+) {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    (Arc::new(ForwardingListener(tx)), rx)
+}
+
+/// A transport that records the batches of messages sent via [`UTransport::send_batch`]
+/// and rejects messages sent individually.
+#[cfg(all(test, feature = "util"))]
+#[derive(Default)]
+pub(crate) struct BatchRecordingTransport {
+    batches: std::sync::Mutex<Vec<Vec<UMessage>>>,
+}
+
+#[cfg(all(test, feature = "util"))]
+impl BatchRecordingTransport {
+    /// Gets the batches that have been sent so far.
+    pub(crate) fn batches(&self) -> Vec<Vec<UMessage>> {
+        self.batches.lock().unwrap().clone()
+    }
+}
+
+#[cfg(all(test, feature = "util"))]
+#[async_trait]
+impl UTransport for BatchRecordingTransport {
+    async fn send(&self, _message: UMessage) -> Result<(), UStatus> {
+        Err(UStatus::fail_with_code(
+            UCode::INTERNAL,
+            "messages must be sent in batches",
+        ))
+    }
+
+    async fn send_batch(&self, messages: Vec<UMessage>) -> Result<(), UStatus> {
+        self.batches.lock().unwrap().push(messages);
+        Ok(())
+    }
+
+    async fn register_listener(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+        _listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        Ok(())
+    }
+
+    async fn unregister_listener(
+        &self,
+        _source_filter: &UUri,
+        _sink_filter: Option<&UUri>,
+        _listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        Ok(())
+    }
+}
+
 /// A wrapper type that allows comparing [`UListener`]s to each other.
 ///
 /// # Note
@@ -0,0 +1,173 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use crate::UMessageType;
+
+/// The guarantees that a transport gives regarding the order in which messages are delivered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MessageOrdering {
+    /// Messages may be delivered in any order.
+    #[default]
+    Unordered,
+    /// Messages sent by the same uEntity are delivered to a listener in the order in which they have been sent.
+    PerSender,
+}
+
+/// A description of the features supported by a [`UTransport`](crate::UTransport) implementation.
+///
+/// The [default](Self::default) descriptor is conservative: it makes no statement about any of the
+/// supported features and does not give any ordering guarantees. Clients should therefore only treat a
+/// feature as missing if a transport explicitly states so.
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{MessageOrdering, TransportCapabilities, UMessageType};
+///
+/// let capabilities = TransportCapabilities::default()
+///     .with_push_delivery(true)
+///     .with_pull_delivery(false)
+///     .with_message_types(&[UMessageType::UMESSAGE_TYPE_PUBLISH])
+///     .with_max_message_size(1024)
+///     .with_ordering(MessageOrdering::PerSender);
+///
+/// assert_eq!(capabilities.supports_push_delivery(), Some(true));
+/// assert_eq!(capabilities.supports_message_type(UMessageType::UMESSAGE_TYPE_REQUEST), Some(false));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransportCapabilities {
+    push_delivery: Option<bool>,
+    pull_delivery: Option<bool>,
+    message_types: Option<Vec<UMessageType>>,
+    max_message_size: Option<usize>,
+    ordering: MessageOrdering,
+}
+
+impl TransportCapabilities {
+    /// Sets whether messages can be consumed by means of listeners.
+    pub fn with_push_delivery(mut self, supported: bool) -> Self {
+        self.push_delivery = Some(supported);
+        self
+    }
+
+    /// Sets whether messages can be consumed by means of [`UTransport::receive`](crate::UTransport::receive).
+    pub fn with_pull_delivery(mut self, supported: bool) -> Self {
+        self.pull_delivery = Some(supported);
+        self
+    }
+
+    /// Sets the types of messages that can be sent and received.
+    pub fn with_message_types(mut self, message_types: &[UMessageType]) -> Self {
+        self.message_types = Some(message_types.to_vec());
+        self
+    }
+
+    /// Sets the maximum size of a message in bytes.
+    ///
+    /// The size refers to the message's attributes and payload as encoded by the transport,
+    /// including any framing overhead. The payload of a message therefore needs to be smaller
+    /// than this limit. Messages exceeding the limit are rejected by the transport.
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = Some(max_message_size);
+        self
+    }
+
+    /// Sets the guarantees regarding the order of delivered messages.
+    pub fn with_ordering(mut self, ordering: MessageOrdering) -> Self {
+        self.ordering = ordering;
+        self
+    }
+
+    /// Checks if messages can be consumed by means of listeners.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the transport does not state whether it supports the push delivery method.
+    pub fn supports_push_delivery(&self) -> Option<bool> {
+        self.push_delivery
+    }
+
+    /// Checks if messages can be consumed by means of [`UTransport::receive`](crate::UTransport::receive).
+    ///
+    /// # Returns
+    ///
+    /// `None` if the transport does not state whether it supports the pull delivery method.
+    pub fn supports_pull_delivery(&self) -> Option<bool> {
+        self.pull_delivery
+    }
+
+    /// Checks if messages of a given type can be sent and received.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the transport does not state which message types it supports.
+    pub fn supports_message_type(&self, message_type: UMessageType) -> Option<bool> {
+        self.message_types
+            .as_ref()
+            .map(|types| types.contains(&message_type))
+    }
+
+    /// Gets the maximum size of an encoded message in bytes.
+    ///
+    /// # Returns
+    ///
+    /// `None` if the transport does not state any limit.
+    pub fn max_message_size(&self) -> Option<usize> {
+        self.max_message_size
+    }
+
+    /// Gets the guarantees regarding the order of delivered messages.
+    pub fn ordering(&self) -> MessageOrdering {
+        self.ordering
+    }
+
+    /// Determines the features that are supported by all of multiple transports.
+    ///
+    /// A feature is considered missing if any of the transports states so, whereas limits and
+    /// guarantees are reduced to the most restrictive ones stated by any of the transports.
+    #[cfg(feature = "util")]
+    pub(crate) fn common_to(capabilities: &[TransportCapabilities]) -> Self {
+        fn all_support(flags: impl Iterator<Item = Option<bool>>) -> Option<bool> {
+            let flags: Vec<Option<bool>> = flags.collect();
+            if flags.contains(&Some(false)) {
+                Some(false)
+            } else if flags.iter().all(|flag| *flag == Some(true)) {
+                Some(true)
+            } else {
+                None
+            }
+        }
+
+        let mut message_types: Option<Vec<UMessageType>> = None;
+        for types in capabilities.iter().filter_map(|c| c.message_types.as_ref()) {
+            message_types = Some(match message_types {
+                None => types.clone(),
+                Some(common) => common.into_iter().filter(|t| types.contains(t)).collect(),
+            });
+        }
+        TransportCapabilities {
+            push_delivery: all_support(capabilities.iter().map(|c| c.push_delivery)),
+            pull_delivery: all_support(capabilities.iter().map(|c| c.pull_delivery)),
+            message_types,
+            max_message_size: capabilities.iter().filter_map(|c| c.max_message_size).min(),
+            ordering: if capabilities
+                .iter()
+                .all(|c| c.ordering == MessageOrdering::PerSender)
+            {
+                MessageOrdering::PerSender
+            } else {
+                MessageOrdering::Unordered
+            },
+        }
+    }
+}
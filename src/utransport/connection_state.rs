@@ -0,0 +1,286 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use crate::{UCode, UStatus};
+
+/// The state of a transport's connection to the underlying network or broker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// Messages can be sent and received.
+    Connected,
+    /// The connection has been lost and will not be re-established.
+    Disconnected,
+    /// The connection has been lost and the transport is trying to re-establish it.
+    Reconnecting,
+}
+
+impl ConnectionState {
+    /// Determines the overall state of multiple connections.
+    ///
+    /// The overall state is the worst of the given states, i.e. [`ConnectionState::Connected`]
+    /// only if all connections are established.
+    #[cfg(feature = "util")]
+    pub(crate) fn worst_of(states: impl IntoIterator<Item = ConnectionState>) -> Self {
+        states
+            .into_iter()
+            .fold(ConnectionState::Connected, |worst, state| {
+                match (worst, state) {
+                    (ConnectionState::Disconnected, _) | (_, ConnectionState::Disconnected) => {
+                        ConnectionState::Disconnected
+                    }
+                    (ConnectionState::Reconnecting, _) | (_, ConnectionState::Reconnecting) => {
+                        ConnectionState::Reconnecting
+                    }
+                    _ => ConnectionState::Connected,
+                }
+            })
+    }
+}
+
+/// A handler for changes of a transport's [`ConnectionState`].
+#[cfg_attr(any(test, feature = "test-util"), mockall::automock)]
+pub trait ConnectionStateListener: Send + Sync {
+    /// Performs some action when the state of a transport's connection has changed.
+    ///
+    /// # Implementation hints
+    ///
+    /// This function is invoked by the transport's internal tasks and is therefore
+    /// expected to return almost immediately.
+    fn on_state_change(&self, state: ConnectionState);
+}
+
+/// Keeps track of a transport's [`ConnectionState`] and informs listeners about changes.
+///
+/// This is a helper for implementing [`UTransport::connection_state`](crate::UTransport::connection_state)
+/// and the related functions for registering listeners.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::{Arc, Mutex};
+/// use up_rust::{ConnectionState, ConnectionStateListener, ConnectionStateNotifier};
+///
+/// #[derive(Default)]
+/// struct StateRecorder(Mutex<Vec<ConnectionState>>);
+///
+/// impl ConnectionStateListener for StateRecorder {
+///     fn on_state_change(&self, state: ConnectionState) {
+///         self.0.lock().unwrap().push(state);
+///     }
+/// }
+///
+/// let notifier = ConnectionStateNotifier::new(ConnectionState::Connected);
+/// let recorder = Arc::new(StateRecorder::default());
+/// notifier.add_listener(recorder.clone()).unwrap();
+///
+/// notifier.set_state(ConnectionState::Reconnecting);
+/// notifier.set_state(ConnectionState::Reconnecting);
+/// notifier.set_state(ConnectionState::Connected);
+/// assert_eq!(
+///     *recorder.0.lock().unwrap(),
+///     vec![ConnectionState::Reconnecting, ConnectionState::Connected]
+/// );
+/// ```
+pub struct ConnectionStateNotifier {
+    state: Mutex<NotificationState>,
+    listeners: Mutex<Vec<Arc<dyn ConnectionStateListener>>>,
+}
+
+struct NotificationState {
+    current: ConnectionState,
+    // state changes that listeners have not been informed about yet
+    pending: VecDeque<ConnectionState>,
+    // indicates whether some thread is currently informing listeners about pending changes
+    notifying: bool,
+}
+
+impl ConnectionStateNotifier {
+    /// Creates a new notifier for an initial state.
+    pub fn new(state: ConnectionState) -> Self {
+        ConnectionStateNotifier {
+            state: Mutex::new(NotificationState {
+                current: state,
+                pending: VecDeque::new(),
+                notifying: false,
+            }),
+            listeners: Mutex::new(vec![]),
+        }
+    }
+
+    /// Gets the current state.
+    pub fn state(&self) -> ConnectionState {
+        self.state
+            .lock()
+            .map_or(ConnectionState::Disconnected, |state| state.current)
+    }
+
+    /// Sets the current state.
+    ///
+    /// All registered listeners are informed if the state differs from the previous state.
+    /// Listeners are always informed about changes in the order in which they have occurred.
+    /// No locks are being held while listeners are invoked, so listeners may safely call back
+    /// into this notifier. However, if another thread is already informing listeners about a
+    /// previous change, this function returns right away and leaves it to the other thread to
+    /// also inform the listeners about this change.
+    pub fn set_state(&self, new_state: ConnectionState) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.current == new_state {
+            return;
+        }
+        state.current = new_state;
+        state.pending.push_back(new_state);
+        if state.notifying {
+            return;
+        }
+        state.notifying = true;
+        while let Some(next_state) = state.pending.pop_front() {
+            drop(state);
+            let listeners = self
+                .listeners
+                .lock()
+                .map(|listeners| listeners.clone())
+                .unwrap_or_default();
+            listeners
+                .iter()
+                .for_each(|listener| listener.on_state_change(next_state));
+            let Ok(next) = self.state.lock() else {
+                return;
+            };
+            state = next;
+        }
+        state.notifying = false;
+    }
+
+    /// Adds a listener to inform about state changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::ALREADY_EXISTS`] if the listener has already been added.
+    pub fn add_listener(&self, listener: Arc<dyn ConnectionStateListener>) -> Result<(), UStatus> {
+        let mut listeners = self.lock_listeners()?;
+        if listeners
+            .iter()
+            .any(|existing| Arc::ptr_eq(existing, &listener))
+        {
+            return Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "listener has already been added",
+            ));
+        }
+        listeners.push(listener);
+        Ok(())
+    }
+
+    /// Removes a listener.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with [`UCode::NOT_FOUND`] if the listener has not been added before.
+    pub fn remove_listener(
+        &self,
+        listener: Arc<dyn ConnectionStateListener>,
+    ) -> Result<(), UStatus> {
+        let mut listeners = self.lock_listeners()?;
+        let Some(index) = listeners
+            .iter()
+            .position(|existing| Arc::ptr_eq(existing, &listener))
+        else {
+            return Err(UStatus::fail_with_code(
+                UCode::NOT_FOUND,
+                "no such listener",
+            ));
+        };
+        listeners.remove(index);
+        Ok(())
+    }
+
+    fn lock_listeners(
+        &self,
+    ) -> Result<std::sync::MutexGuard<'_, Vec<Arc<dyn ConnectionStateListener>>>, UStatus> {
+        self.listeners.lock().map_err(|_e| {
+            UStatus::fail_with_code(UCode::INTERNAL, "failed to acquire lock for listeners")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_removed_listener_is_not_informed() {
+        let mut listener = MockConnectionStateListener::new();
+        listener
+            .expect_on_state_change()
+            .once()
+            .withf(|state| *state == ConnectionState::Reconnecting)
+            .return_const(());
+        let listener: Arc<dyn ConnectionStateListener> = Arc::new(listener);
+        let notifier = ConnectionStateNotifier::new(ConnectionState::Connected);
+
+        assert!(notifier.add_listener(listener.clone()).is_ok());
+        assert!(notifier
+            .add_listener(listener.clone())
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+        notifier.set_state(ConnectionState::Reconnecting);
+
+        assert!(notifier.remove_listener(listener.clone()).is_ok());
+        assert!(notifier
+            .remove_listener(listener)
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+        notifier.set_state(ConnectionState::Connected);
+        assert_eq!(notifier.state(), ConnectionState::Connected);
+    }
+
+    #[test]
+    fn test_listener_can_change_state_while_being_informed() {
+        #[derive(Default)]
+        struct ReconnectingListener {
+            notifier: std::sync::OnceLock<Arc<ConnectionStateNotifier>>,
+            states: Mutex<Vec<ConnectionState>>,
+        }
+
+        impl ConnectionStateListener for ReconnectingListener {
+            fn on_state_change(&self, state: ConnectionState) {
+                self.states.lock().unwrap().push(state);
+                if state == ConnectionState::Reconnecting {
+                    // simulate a connection that is re-established right away
+                    self.notifier
+                        .get()
+                        .unwrap()
+                        .set_state(ConnectionState::Connected);
+                }
+            }
+        }
+
+        let notifier = Arc::new(ConnectionStateNotifier::new(ConnectionState::Connected));
+        let listener = Arc::new(ReconnectingListener::default());
+        let _ = listener.notifier.set(notifier.clone());
+        notifier.add_listener(listener.clone()).unwrap();
+
+        notifier.set_state(ConnectionState::Reconnecting);
+
+        assert_eq!(
+            *listener.states.lock().unwrap(),
+            vec![ConnectionState::Reconnecting, ConnectionState::Connected]
+        );
+        assert_eq!(notifier.state(), ConnectionState::Connected);
+    }
+}
@@ -0,0 +1,449 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::{UMessage, UUri};
+
+/// The key for looking up a UUri pattern's non-authority parts.
+///
+/// A `None` value represents the wildcard for the corresponding part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct EntityKey {
+    entity_type: Option<u16>,
+    entity_instance: Option<u16>,
+    entity_version: Option<u8>,
+    resource_id: Option<u32>,
+}
+
+impl EntityKey {
+    fn for_pattern(pattern: &UUri) -> Self {
+        EntityKey {
+            entity_type: (!pattern.has_wildcard_entity_type()).then(|| pattern.uentity_type_id()),
+            entity_instance: (!pattern.has_wildcard_entity_instance())
+                .then(|| pattern.uentity_instance_id()),
+            entity_version: (!pattern.has_wildcard_version())
+                .then(|| pattern.uentity_major_version()),
+            resource_id: (!pattern.has_wildcard_resource_id()).then_some(pattern.resource_id),
+        }
+    }
+
+    /// Gets the keys of all patterns that match a given URI.
+    fn matching_keys(candidate: &UUri) -> [EntityKey; 16] {
+        let entity_type = candidate.uentity_type_id();
+        let entity_instance = candidate.uentity_instance_id();
+        let entity_version = candidate.uentity_major_version();
+        let resource_id = candidate.resource_id;
+        // each of the four parts either matches exactly or by means of a wildcard
+        std::array::from_fn(|wildcards| EntityKey {
+            entity_type: (wildcards & 0b0001 == 0).then_some(entity_type),
+            entity_instance: (wildcards & 0b0010 == 0).then_some(entity_instance),
+            entity_version: (wildcards & 0b0100 == 0).then_some(entity_version),
+            resource_id: (wildcards & 0b1000 == 0).then_some(resource_id),
+        })
+    }
+}
+
+/// Maps UUri patterns to values.
+struct PatternIndex<V> {
+    authorities: HashMap<String, HashMap<EntityKey, V>>,
+    any_authority: HashMap<EntityKey, V>,
+}
+
+impl<V> Default for PatternIndex<V> {
+    fn default() -> Self {
+        PatternIndex {
+            authorities: HashMap::new(),
+            any_authority: HashMap::new(),
+        }
+    }
+}
+
+impl<V: Default> PatternIndex<V> {
+    fn entry(&mut self, pattern: &UUri) -> &mut V {
+        let entities = if pattern.has_wildcard_authority() {
+            &mut self.any_authority
+        } else {
+            self.authorities
+                .entry(pattern.authority_name.to_owned())
+                .or_default()
+        };
+        entities.entry(EntityKey::for_pattern(pattern)).or_default()
+    }
+
+    fn get(&self, pattern: &UUri) -> Option<&V> {
+        let entities = if pattern.has_wildcard_authority() {
+            Some(&self.any_authority)
+        } else {
+            self.authorities.get(pattern.authority_name.as_str())
+        };
+        entities.and_then(|entities| entities.get(&EntityKey::for_pattern(pattern)))
+    }
+
+    fn get_mut(&mut self, pattern: &UUri) -> Option<&mut V> {
+        let entities = if pattern.has_wildcard_authority() {
+            Some(&mut self.any_authority)
+        } else {
+            self.authorities.get_mut(pattern.authority_name.as_str())
+        };
+        entities.and_then(|entities| entities.get_mut(&EntityKey::for_pattern(pattern)))
+    }
+
+    /// Removes the value for a pattern, if the given predicate holds for it.
+    fn remove_if(&mut self, pattern: &UUri, predicate: impl FnOnce(&V) -> bool) {
+        let key = EntityKey::for_pattern(pattern);
+        if pattern.has_wildcard_authority() {
+            if self.any_authority.get(&key).is_some_and(predicate) {
+                self.any_authority.remove(&key);
+            }
+        } else if let Some(entities) = self.authorities.get_mut(pattern.authority_name.as_str()) {
+            if entities.get(&key).is_some_and(predicate) {
+                entities.remove(&key);
+            }
+            if entities.is_empty() {
+                self.authorities.remove(pattern.authority_name.as_str());
+            }
+        }
+    }
+
+    /// Gets the values of all patterns that match a given URI.
+    fn matches<'a>(&'a self, candidate: &UUri) -> impl Iterator<Item = &'a V> + 'a {
+        let exact_authority = self.authorities.get(candidate.authority_name.as_str());
+        let keys = EntityKey::matching_keys(candidate);
+        [Some(&self.any_authority), exact_authority]
+            .into_iter()
+            .flatten()
+            .filter(|entities| !entities.is_empty())
+            .flat_map(move |entities| keys.into_iter().filter_map(move |key| entities.get(&key)))
+    }
+
+    fn values(&self) -> impl Iterator<Item = &V> {
+        self.authorities
+            .values()
+            .flat_map(|entities| entities.values())
+            .chain(self.any_authority.values())
+    }
+}
+
+/// The values registered for a particular source filter.
+struct SinkFilters<T> {
+    // values registered for a `None` sink filter
+    no_sink: HashSet<T>,
+    sink_patterns: PatternIndex<HashSet<T>>,
+}
+
+impl<T> Default for SinkFilters<T> {
+    fn default() -> Self {
+        SinkFilters {
+            no_sink: HashSet::new(),
+            sink_patterns: PatternIndex::default(),
+        }
+    }
+}
+
+impl<T> SinkFilters<T> {
+    fn is_empty(&self) -> bool {
+        self.no_sink.is_empty() && self.sink_patterns.values().all(HashSet::is_empty)
+    }
+}
+
+/// An index of values (typically listeners) that have been registered for source and sink filter patterns.
+///
+/// The index supports efficient lookup of all values registered for filters that match a given
+/// message's source and sink address. Filters are organized by authority, uEntity type, uEntity
+/// instance, major version and resource ID, with separate buckets for wildcard values. The effort for
+/// looking up matching values therefore does not depend on the overall number of registered filters.
+///
+/// The index can be used by [`UTransport`](crate::UTransport) implementations for keeping track of
+/// registered [`UListener`](crate::UListener)s, e.g. by means of [`ComparableListener`](crate::ComparableListener)s.
+/// Note that the index itself does not check if the filters are valid. Transport implementations should
+/// use [`verify_filter_criteria`](crate::verify_filter_criteria) for that purpose before adding filters
+/// to the index.
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{FilterIndex, UUri};
+///
+/// let mut index = FilterIndex::default();
+/// let topic_filter = UUri::try_from("//my-vehicle/A100/1/FFFF").unwrap();
+/// assert!(index.insert(&topic_filter, None, "listener-1"));
+///
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// assert_eq!(index.find_matches(&topic, None), vec![&"listener-1"]);
+/// let other_topic = UUri::try_from("//other-vehicle/A100/1/8001").unwrap();
+/// assert!(index.find_matches(&other_topic, None).is_empty());
+/// ```
+pub struct FilterIndex<T> {
+    source_patterns: PatternIndex<SinkFilters<T>>,
+    len: usize,
+}
+
+impl<T> Default for FilterIndex<T> {
+    fn default() -> Self {
+        FilterIndex {
+            source_patterns: PatternIndex::default(),
+            len: 0,
+        }
+    }
+}
+
+impl<T: Eq + Hash> FilterIndex<T> {
+    /// Creates a new, empty index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of registrations in this index.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if this index contains any registrations.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds a value for given filter patterns.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_filter` - The _source_ address pattern that messages need to match.
+    /// * `sink_filter` - The _sink_ address pattern that messages need to match,
+    ///   or `None` to match messages that do not contain any sink address.
+    /// * `value` - The value to register.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the same value has already been registered for the given filters.
+    pub fn insert(&mut self, source_filter: &UUri, sink_filter: Option<&UUri>, value: T) -> bool {
+        let sink_filters = self.source_patterns.entry(source_filter);
+        let values = match sink_filter {
+            Some(pattern) => sink_filters.sink_patterns.entry(pattern),
+            None => &mut sink_filters.no_sink,
+        };
+        let inserted = values.insert(value);
+        if inserted {
+            self.len += 1;
+        } else {
+            // make sure to not leave behind empty entries
+            self.cleanup(source_filter, sink_filter);
+        }
+        inserted
+    }
+
+    /// Removes a value that has been registered for given filter patterns.
+    ///
+    /// # Returns
+    ///
+    /// The value that has been removed from the index or `None` if the value
+    /// had not been registered for the given filters.
+    pub fn remove(
+        &mut self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        value: &T,
+    ) -> Option<T> {
+        let sink_filters = self.source_patterns.get_mut(source_filter)?;
+        let values = match sink_filter {
+            Some(pattern) => sink_filters.sink_patterns.get_mut(pattern)?,
+            None => &mut sink_filters.no_sink,
+        };
+        let removed = values.take(value);
+        if removed.is_some() {
+            self.len -= 1;
+            self.cleanup(source_filter, sink_filter);
+        }
+        removed
+    }
+
+    /// Checks if a value has been registered for given filter patterns.
+    pub fn contains(&self, source_filter: &UUri, sink_filter: Option<&UUri>, value: &T) -> bool {
+        self.get(source_filter, sink_filter)
+            .is_some_and(|values| values.contains(value))
+    }
+
+    /// Gets all values that have been registered for exactly the given filter patterns.
+    pub fn get(&self, source_filter: &UUri, sink_filter: Option<&UUri>) -> Option<&HashSet<T>> {
+        let sink_filters = self.source_patterns.get(source_filter)?;
+        match sink_filter {
+            Some(pattern) => sink_filters.sink_patterns.get(pattern),
+            None => Some(&sink_filters.no_sink),
+        }
+    }
+
+    /// Gets all values that have been registered for filters matching a given source and sink address.
+    ///
+    /// A value that has been registered for multiple matching filters is included once for each filter.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The source address to match.
+    /// * `sink` - The sink address to match or `None` to only match values that have been
+    ///   registered with an empty sink filter.
+    pub fn find_matches(&self, source: &UUri, sink: Option<&UUri>) -> Vec<&T> {
+        self.source_patterns
+            .matches(source)
+            .flat_map(|sink_filters| -> Box<dyn Iterator<Item = &T> + '_> {
+                match sink {
+                    Some(sink_uri) => Box::new(
+                        sink_filters
+                            .sink_patterns
+                            .matches(sink_uri)
+                            .flat_map(HashSet::iter),
+                    ),
+                    None => Box::new(sink_filters.no_sink.iter()),
+                }
+            })
+            .collect()
+    }
+
+    /// Gets all values that have been registered for filters matching a given message's source and sink address.
+    ///
+    /// # Returns
+    ///
+    /// The values or an empty vector if the message does not contain a source address.
+    pub fn find_matches_for_message(&self, message: &UMessage) -> Vec<&T> {
+        message
+            .source()
+            .map_or(vec![], |source| self.find_matches(source, message.sink()))
+    }
+
+    /// Gets an iterator over all values contained in this index.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.source_patterns.values().flat_map(|sink_filters| {
+            sink_filters
+                .no_sink
+                .iter()
+                .chain(sink_filters.sink_patterns.values().flat_map(HashSet::iter))
+        })
+    }
+
+    fn cleanup(&mut self, source_filter: &UUri, sink_filter: Option<&UUri>) {
+        if let Some(sink_filters) = self.source_patterns.get_mut(source_filter) {
+            if let Some(pattern) = sink_filter {
+                sink_filters
+                    .sink_patterns
+                    .remove_if(pattern, HashSet::is_empty);
+            }
+        }
+        self.source_patterns
+            .remove_if(source_filter, SinkFilters::is_empty);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    use crate::UMessageBuilder;
+
+    fn uri(value: &str) -> UUri {
+        UUri::try_from(value).expect("invalid URI")
+    }
+
+    #[test_case("//vehicle1/AA/1/8001", None, "//vehicle1/AA/1/8001", None, true; "for identical source")]
+    #[test_case("//*/AA/1/8001", None, "//vehicle1/AA/1/8001", None, true; "for wildcard authority")]
+    #[test_case("//vehicle1/FFFF/1/8001", None, "//vehicle1/AA/1/8001", None, true; "for wildcard entity type")]
+    #[test_case("//vehicle1/FFFF00AA/1/8001", None, "//vehicle1/100AA/1/8001", None, true; "for wildcard entity instance")]
+    #[test_case("//vehicle1/AA/FF/8001", None, "//vehicle1/AA/1/8001", None, true; "for wildcard version")]
+    #[test_case("//vehicle1/AA/1/FFFF", None, "//vehicle1/AA/1/8001", None, true; "for wildcard resource")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", None, "//vehicle1/AA/1/8001", None, true; "for all wildcards")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", Some("//vehicle2/BB/1/0"), "//vehicle1/AA/1/1", Some("//vehicle2/BB/1/0"), true; "for identical sink")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", Some("//*/FFFFFFFF/FF/0"), "//vehicle1/AA/1/1", Some("//vehicle2/BB/1/0"), true; "for wildcard sink")]
+    #[test_case("//vehicle2/AA/1/8001", None, "//vehicle1/AA/1/8001", None, false; "for different authority")]
+    #[test_case("//vehicle1/AB/1/8001", None, "//vehicle1/AA/1/8001", None, false; "for different entity type")]
+    #[test_case("//vehicle1/100AA/1/8001", None, "//vehicle1/AA/1/8001", None, false; "for different entity instance")]
+    #[test_case("//vehicle1/AA/2/8001", None, "//vehicle1/AA/1/8001", None, false; "for different version")]
+    #[test_case("//vehicle1/AA/1/8002", None, "//vehicle1/AA/1/8001", None, false; "for different resource")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", None, "//vehicle1/AA/1/1", Some("//vehicle2/BB/1/0"), false; "for missing sink filter")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", Some("//*/FFFFFFFF/FF/0"), "//vehicle1/AA/1/8001", None, false; "for missing sink")]
+    #[test_case("//*/FFFFFFFF/FF/FFFF", Some("//vehicle3/FFFFFFFF/FF/0"), "//vehicle1/AA/1/1", Some("//vehicle2/BB/1/0"), false; "for different sink")]
+    fn test_find_matches_is_consistent_with_uuri_matches(
+        source_filter: &str,
+        sink_filter: Option<&str>,
+        source: &str,
+        sink: Option<&str>,
+        should_match: bool,
+    ) {
+        let source_filter = uri(source_filter);
+        let sink_filter = sink_filter.map(uri);
+        let source = uri(source);
+        let sink = sink.map(uri);
+
+        let expected_match = source_filter.matches(&source)
+            && match (&sink_filter, &sink) {
+                (Some(pattern), Some(candidate)) => pattern.matches(candidate),
+                (None, None) => true,
+                _ => false,
+            };
+        assert_eq!(expected_match, should_match);
+
+        let mut index = FilterIndex::new();
+        index.insert(&source_filter, sink_filter.as_ref(), 1_u8);
+        let matches = index.find_matches(&source, sink.as_ref());
+        assert_eq!(!matches.is_empty(), should_match);
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut index = FilterIndex::new();
+        let source_filter = uri("//vehicle1/AA/1/FFFF");
+        let sink_filter = uri("//vehicle2/BB/1/0");
+
+        assert!(index.insert(&source_filter, None, "one"));
+        assert!(!index.insert(&source_filter, None, "one"));
+        assert!(index.insert(&source_filter, Some(&sink_filter), "one"));
+        assert!(index.insert(&source_filter, Some(&sink_filter), "two"));
+        assert_eq!(index.len(), 3);
+        assert!(index.contains(&source_filter, Some(&sink_filter), &"two"));
+        assert_eq!(index.values().count(), 3);
+
+        assert_eq!(index.remove(&source_filter, None, &"two"), None);
+        assert_eq!(
+            index.remove(&source_filter, Some(&sink_filter), &"two"),
+            Some("two")
+        );
+        assert!(!index.contains(&source_filter, Some(&sink_filter), &"two"));
+        assert_eq!(index.remove(&source_filter, None, &"one"), Some("one"));
+        assert_eq!(
+            index.remove(&source_filter, Some(&sink_filter), &"one"),
+            Some("one")
+        );
+        assert!(index.is_empty());
+        // all buckets have been removed
+        assert!(index.source_patterns.authorities.is_empty());
+        assert!(index.source_patterns.any_authority.is_empty());
+    }
+
+    #[test]
+    fn test_find_matches_for_message_includes_all_matching_registrations() {
+        let mut index = FilterIndex::new();
+        let topic = uri("//vehicle1/AA/1/8001");
+        index.insert(&topic, None, "exact");
+        index.insert(&uri("//*/FFFFFFFF/FF/FFFF"), None, "any");
+        index.insert(&uri("//vehicle1/AA/1/FFFF"), None, "exact");
+        index.insert(&uri("//vehicle1/AA/1/8002"), None, "other");
+        index.insert(&topic, Some(&uri("//vehicle2/BB/1/0")), "with sink");
+
+        let message = UMessageBuilder::publish(topic).build().unwrap();
+        let mut matches = index.find_matches_for_message(&message);
+        matches.sort();
+        assert_eq!(matches, vec![&"any", &"exact", &"exact"]);
+        assert!(index
+            .find_matches_for_message(&UMessage::default())
+            .is_empty());
+    }
+}
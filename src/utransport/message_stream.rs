@@ -0,0 +1,232 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use async_trait::async_trait;
+use futures_core::Stream;
+use tokio::sync::mpsc;
+use tracing::debug;
+
+use crate::{UListener, UMessage, UStatus, UTransport, UUri};
+
+/// A listener that forwards messages to a [`MessageStream`].
+struct StreamListener {
+    messages: mpsc::Sender<UMessage>,
+}
+
+#[async_trait]
+impl UListener for StreamListener {
+    async fn on_receive(&self, msg: UMessage) {
+        if let Err(mpsc::error::TrySendError::Full(_msg)) = self.messages.try_send(msg) {
+            debug!("message stream buffer is full, discarding message");
+        }
+    }
+}
+
+/// The filters and listener that a [`MessageStream`] has been registered with.
+struct Registration {
+    transport: Arc<dyn UTransport>,
+    source_filter: UUri,
+    sink_filter: Option<UUri>,
+    listener: Arc<dyn UListener>,
+}
+
+impl Registration {
+    async fn unregister(self) -> Result<(), UStatus> {
+        self.transport
+            .unregister_listener(
+                &self.source_filter,
+                self.sink_filter.as_ref(),
+                self.listener,
+            )
+            .await
+    }
+}
+
+/// A [`Stream`] of messages received via a [`UTransport`].
+///
+/// The stream is backed by a listener that has been registered with the transport. The listener
+/// puts all received messages into a bounded buffer. Messages that arrive while the buffer is full
+/// are discarded, so that a slow consumer does not block the transport.
+///
+/// The listener is unregistered from the transport when the stream is being dropped. Use
+/// [`MessageStream::close`] for unregistering the listener explicitly.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use tokio_stream::StreamExt;
+/// use up_rust::{local_transport::LocalTransport, MessageStream, UMessageBuilder, UTransport, UUri};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = Arc::new(LocalTransport::default());
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let mut messages = MessageStream::register(transport.clone(), &topic, None, 10)
+///     .await
+///     .unwrap();
+///
+/// let msg = UMessageBuilder::publish(topic).build().unwrap();
+/// transport.send(msg.clone()).await.unwrap();
+/// assert_eq!(messages.next().await, Some(msg));
+/// # }
+/// ```
+pub struct MessageStream {
+    messages: mpsc::Receiver<UMessage>,
+    registration: Option<Registration>,
+}
+
+impl MessageStream {
+    /// Registers a listener with a transport and creates a stream of the messages received by it.
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The transport to register the listener with.
+    /// * `source_filter` - The _source_ address pattern that messages need to match.
+    /// * `sink_filter` - The _sink_ address pattern that messages need to match,
+    ///   or `None` to match messages that do not contain any sink address.
+    /// * `buffer_size` - The maximum number of messages to buffer. A value of 0 is treated as 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be registered with the transport.
+    pub async fn register(
+        transport: Arc<dyn UTransport>,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        buffer_size: usize,
+    ) -> Result<Self, UStatus> {
+        let (messages_tx, messages_rx) = mpsc::channel(buffer_size.max(1));
+        let listener: Arc<dyn UListener> = Arc::new(StreamListener {
+            messages: messages_tx,
+        });
+        transport
+            .register_listener(source_filter, sink_filter, listener.clone())
+            .await?;
+        Ok(MessageStream {
+            messages: messages_rx,
+            registration: Some(Registration {
+                transport,
+                source_filter: source_filter.to_owned(),
+                sink_filter: sink_filter.cloned(),
+                listener,
+            }),
+        })
+    }
+
+    /// Unregisters the listener from the transport.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be unregistered from the transport.
+    pub async fn close(mut self) -> Result<(), UStatus> {
+        match self.registration.take() {
+            Some(registration) => registration.unregister().await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Stream for MessageStream {
+    type Item = UMessage;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.messages.poll_recv(cx)
+    }
+}
+
+impl Drop for MessageStream {
+    fn drop(&mut self) {
+        let Some(registration) = self.registration.take() else {
+            return;
+        };
+        // unregistering requires an async runtime
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                runtime.spawn(async move {
+                    if let Err(e) = registration.unregister().await {
+                        debug!("failed to unregister listener of dropped message stream: {e}");
+                    }
+                });
+            }
+            Err(_e) => debug!("cannot unregister listener of message stream outside of runtime"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tokio_stream::StreamExt;
+
+    use crate::{utransport::MockTransport, UMessageBuilder};
+
+    #[tokio::test]
+    async fn test_stream_unregisters_listener_when_dropped() {
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let registered_listener = Arc::new(std::sync::Mutex::new(None));
+        let (unregistered_tx, mut unregistered_rx) = mpsc::unbounded_channel();
+        let mut transport = MockTransport::new();
+        let captured_listener = registered_listener.clone();
+        transport.expect_do_register_listener().once().returning(
+            move |_source_filter, _sink_filter, listener| {
+                *captured_listener.lock().unwrap() = Some(listener);
+                Ok(())
+            },
+        );
+        let expected_topic = topic.clone();
+        transport
+            .expect_do_unregister_listener()
+            .once()
+            .withf(move |source_filter, sink_filter, _listener| {
+                source_filter == &expected_topic && sink_filter.is_none()
+            })
+            .returning(move |_source_filter, _sink_filter, _listener| {
+                let _ = unregistered_tx.send(());
+                Ok(())
+            });
+
+        // GIVEN a stream with a buffer for two messages
+        let mut messages = MessageStream::register(Arc::new(transport), &topic, None, 2)
+            .await
+            .unwrap();
+
+        // WHEN three messages arrive
+        let listener = registered_listener.lock().unwrap().take().unwrap();
+        for _i in 0..3 {
+            listener
+                .on_receive(UMessageBuilder::publish(topic.clone()).build().unwrap())
+                .await;
+        }
+
+        // THEN only the first two messages are available from the stream
+        assert!(messages.next().await.is_some());
+        assert!(messages.next().await.is_some());
+        assert!(
+            tokio::time::timeout(std::time::Duration::from_millis(50), messages.next())
+                .await
+                .is_err()
+        );
+
+        // and the listener is unregistered when the stream is dropped
+        drop(messages);
+        assert!(unregistered_rx.recv().await.is_some());
+    }
+}
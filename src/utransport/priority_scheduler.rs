@@ -0,0 +1,197 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::collections::VecDeque;
+
+use crate::{UMessage, UPriority};
+
+// the number of priority classes CS0 to CS6
+const PRIORITY_CLASSES: usize = 7;
+
+/// Gets the index of the queue to put a message into.
+///
+/// Messages without a (valid) priority are treated as having the default priority.
+fn queue_index(message: &UMessage) -> usize {
+    let priority = message.priority().unwrap_or(UPriority::UPRIORITY_CS1);
+    // UPRIORITY_CS0 has value 1, UPRIORITY_CS6 has value 7
+    (priority as usize).clamp(1, PRIORITY_CLASSES) - 1
+}
+
+/// A queue of messages that are taken out in the order of their [`UPriority`].
+///
+/// Messages with a higher priority are always taken out before messages with a lower
+/// priority. Messages of the same priority are taken out in the order in which they have been added.
+/// Messages that do not have a priority are treated as having the default priority
+/// ([`UPriority::UPRIORITY_CS1`]).
+///
+/// The scheduler can be used by [`UTransport`](crate::UTransport) implementations for making
+/// sure that urgent messages, e.g. safety-related RPC requests with priority `CS6`, are not
+/// delayed by a large number of less important messages, e.g. telemetry data published with `CS1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{PriorityScheduler, UMessageBuilder, UPriority, UUri};
+///
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let telemetry = UMessageBuilder::publish(topic.clone())
+///     .with_priority(UPriority::UPRIORITY_CS1)
+///     .build()
+///     .unwrap();
+/// let alert = UMessageBuilder::publish(topic)
+///     .with_priority(UPriority::UPRIORITY_CS6)
+///     .build()
+///     .unwrap();
+///
+/// let mut scheduler = PriorityScheduler::new();
+/// scheduler.push(telemetry.clone());
+/// scheduler.push(alert.clone());
+/// assert_eq!(scheduler.pop(), Some(alert));
+/// assert_eq!(scheduler.pop(), Some(telemetry));
+/// assert!(scheduler.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct PriorityScheduler {
+    // one queue per priority class, index 0 holds messages with priority CS0
+    queues: [VecDeque<UMessage>; PRIORITY_CLASSES],
+    len: usize,
+}
+
+impl PriorityScheduler {
+    /// Creates a new, empty scheduler.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Gets the number of messages in this scheduler.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks if this scheduler contains any messages.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds a message.
+    pub fn push(&mut self, message: UMessage) {
+        self.queues[queue_index(&message)].push_back(message);
+        self.len += 1;
+    }
+
+    /// Takes out the message that is up next.
+    ///
+    /// # Returns
+    ///
+    /// The oldest message having the highest priority or `None` if the scheduler is empty.
+    pub fn pop(&mut self) -> Option<UMessage> {
+        let message = self
+            .queues
+            .iter_mut()
+            .rev()
+            .find_map(|queue| queue.pop_front())?;
+        self.len -= 1;
+        Some(message)
+    }
+
+    /// Gets the message that is up next without taking it out.
+    pub fn peek(&self) -> Option<&UMessage> {
+        self.queues.iter().rev().find_map(|queue| queue.front())
+    }
+
+    /// Takes out the least important message.
+    ///
+    /// This is useful for making room for new messages if the number of messages needs to be limited.
+    ///
+    /// # Returns
+    ///
+    /// The oldest message having the lowest priority or `None` if the scheduler is empty.
+    pub fn pop_lowest(&mut self) -> Option<UMessage> {
+        let message = self.queues.iter_mut().find_map(|queue| queue.pop_front())?;
+        self.len -= 1;
+        Some(message)
+    }
+
+    /// Removes all messages.
+    pub fn clear(&mut self) {
+        self.queues.iter_mut().for_each(VecDeque::clear);
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{UMessageBuilder, UUri};
+
+    fn new_message(priority: UPriority) -> UMessage {
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        UMessageBuilder::publish(topic)
+            .with_priority(priority)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_pop_returns_messages_in_priority_order() {
+        let low_1 = new_message(UPriority::UPRIORITY_CS0);
+        let default_1 = new_message(UPriority::UPRIORITY_CS1);
+        let high_1 = new_message(UPriority::UPRIORITY_CS6);
+        let default_2 = new_message(UPriority::UPRIORITY_CS1);
+        let high_2 = new_message(UPriority::UPRIORITY_CS6);
+        let mut unspecified = new_message(UPriority::UPRIORITY_CS1);
+        unspecified.attributes.as_mut().unwrap().priority = UPriority::UPRIORITY_UNSPECIFIED.into();
+
+        let mut scheduler = PriorityScheduler::new();
+        for msg in [
+            &low_1,
+            &default_1,
+            &high_1,
+            &default_2,
+            &high_2,
+            &unspecified,
+        ] {
+            scheduler.push(msg.to_owned());
+        }
+        assert_eq!(scheduler.len(), 6);
+        assert_eq!(scheduler.peek(), Some(&high_1));
+
+        let popped: Vec<UMessage> = std::iter::from_fn(|| scheduler.pop()).collect();
+        assert_eq!(
+            popped,
+            vec![high_1, high_2, default_1, default_2, unspecified, low_1]
+        );
+        assert!(scheduler.is_empty());
+        assert!(scheduler.pop().is_none());
+    }
+
+    #[test]
+    fn test_pop_lowest_returns_oldest_least_important_message() {
+        let default_1 = new_message(UPriority::UPRIORITY_CS1);
+        let default_2 = new_message(UPriority::UPRIORITY_CS1);
+        let high = new_message(UPriority::UPRIORITY_CS5);
+
+        let mut scheduler = PriorityScheduler::new();
+        scheduler.push(high.clone());
+        scheduler.push(default_1.clone());
+        scheduler.push(default_2.clone());
+
+        assert_eq!(scheduler.pop_lowest(), Some(default_1));
+        assert_eq!(scheduler.pop_lowest(), Some(default_2));
+        assert_eq!(scheduler.len(), 1);
+        scheduler.clear();
+        assert!(scheduler.is_empty());
+        assert!(scheduler.pop_lowest().is_none());
+    }
+}
@@ -0,0 +1,428 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    collections::VecDeque,
+    pin::pin,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use tokio::sync::Notify;
+
+use crate::{
+    utransport::FilterIndex, ComparableListener, UCode, UListener, UMessage, UStatus, UTransport,
+    UUri, UUID,
+};
+
+#[derive(Default)]
+struct Recording {
+    // all messages that have been sent
+    sent: Vec<UMessage>,
+    // the sent messages that have not been matched by an expectation yet
+    unmatched: VecDeque<UMessage>,
+    send_error: Option<UStatus>,
+}
+
+/// A [`UTransport`] for testing code that sends messages and registers listeners.
+///
+/// In contrast to [`MockTransport`](crate::MockTransport), this transport does not require any
+/// expectations to be set up front. Instead, it records all messages that are being sent and all listeners
+/// that are being registered. Tests can then use the `expect_*` functions for asserting that particular
+/// messages have been sent, and [inject](Self::inject) inbound messages into the registered listeners.
+///
+/// Each `expect_*` function consumes the first recorded message that matches the expectation and has not
+/// been consumed by any previous expectation. This allows expecting multiple messages of the same kind
+/// one after the other.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use up_rust::{RecordingTransport, UMessageBuilder, UTransport, UUri};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = RecordingTransport::default();
+/// let method = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+/// let reply_to = UUri::try_from("//my-vehicle/B100/1/0").unwrap();
+///
+/// let request = UMessageBuilder::request(method.clone(), reply_to, 5000).build().unwrap();
+/// transport.send(request).await.unwrap();
+///
+/// let request = transport
+///     .expect_request(&method, Duration::from_millis(100))
+///     .await;
+/// assert_eq!(request.ttl(), Some(5000));
+/// # }
+/// ```
+#[derive(Default)]
+pub struct RecordingTransport {
+    recording: Mutex<Recording>,
+    message_sent: Notify,
+    listeners: Mutex<FilterIndex<ComparableListener>>,
+}
+
+impl RecordingTransport {
+    /// Sets the error to return from [`UTransport::send`].
+    ///
+    /// Messages are neither recorded nor delivered while an error is set.
+    /// Passing `None` lets the transport accept messages again.
+    pub fn set_send_error(&self, error: Option<UStatus>) {
+        self.lock_recording().send_error = error;
+    }
+
+    /// Gets all messages that have been sent so far, in the order in which they have been sent.
+    pub fn sent_messages(&self) -> Vec<UMessage> {
+        self.lock_recording().sent.clone()
+    }
+
+    /// Discards all messages that have been recorded so far.
+    pub fn clear(&self) {
+        let mut recording = self.lock_recording();
+        recording.sent.clear();
+        recording.unmatched.clear();
+    }
+
+    /// Gets the number of currently registered listeners.
+    pub fn listener_count(&self) -> usize {
+        self.lock_listeners().len()
+    }
+
+    /// Checks if any listener is registered for the given filters.
+    pub fn has_listener(&self, source_filter: &UUri, sink_filter: Option<&UUri>) -> bool {
+        self.lock_listeners()
+            .get(source_filter, sink_filter)
+            .is_some_and(|listeners| !listeners.is_empty())
+    }
+
+    /// Delivers an inbound message to all registered listeners whose filters match the message's
+    /// source and sink.
+    ///
+    /// # Returns
+    ///
+    /// The number of listeners that the message has been delivered to.
+    pub async fn inject(&self, message: UMessage) -> usize {
+        let listeners: Vec<ComparableListener> = self
+            .lock_listeners()
+            .find_matches_for_message(&message)
+            .into_iter()
+            .cloned()
+            .collect();
+        for listener in &listeners {
+            listener.on_receive(message.clone()).await;
+        }
+        listeners.len()
+    }
+
+    /// Waits for a message matching a predicate to be sent.
+    ///
+    /// # Returns
+    ///
+    /// The first sent message that matches the predicate and that has not been returned
+    /// by any previous expectation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no matching message has been sent within the given amount of time.
+    pub async fn expect_message<P>(&self, predicate: P, timeout: Duration) -> UMessage
+    where
+        P: Fn(&UMessage) -> bool,
+    {
+        self.try_expect_message(&predicate, timeout)
+            .await
+            .unwrap_or_else(|| {
+                panic!(
+                    "no matching message has been sent within {timeout:?}, messages sent: {:?}",
+                    self.sent_messages()
+                )
+            })
+    }
+
+    /// Waits for a request message to be sent to an RPC method.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such message has been sent within the given amount of time.
+    pub async fn expect_request(&self, method: &UUri, timeout: Duration) -> UMessage {
+        self.expect_message(
+            |msg| msg.is_request() && msg.sink().is_some_and(|sink| method.matches(sink)),
+            timeout,
+        )
+        .await
+    }
+
+    /// Waits for a response message to be sent for a request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such message has been sent within the given amount of time.
+    pub async fn expect_response(&self, request_id: &UUID, timeout: Duration) -> UMessage {
+        self.expect_message(
+            |msg| msg.is_response() && msg.request_id() == Some(request_id),
+            timeout,
+        )
+        .await
+    }
+
+    /// Waits for a message to be published to a topic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such message has been sent within the given amount of time.
+    pub async fn expect_publish(&self, topic: &UUri, timeout: Duration) -> UMessage {
+        self.expect_message(
+            |msg| msg.is_publish() && msg.source().is_some_and(|source| topic.matches(source)),
+            timeout,
+        )
+        .await
+    }
+
+    /// Waits for a notification to be sent to a destination.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such message has been sent within the given amount of time.
+    pub async fn expect_notification(&self, destination: &UUri, timeout: Duration) -> UMessage {
+        self.expect_message(
+            |msg| msg.is_notification() && msg.sink().is_some_and(|sink| destination.matches(sink)),
+            timeout,
+        )
+        .await
+    }
+
+    /// Verifies that no message matching a predicate is being sent within a given amount of time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a matching message has been sent.
+    pub async fn expect_no_message<P>(&self, predicate: P, duration: Duration)
+    where
+        P: Fn(&UMessage) -> bool,
+    {
+        if let Some(msg) = self.try_expect_message(&predicate, duration).await {
+            panic!("unexpected message has been sent: {msg:?}");
+        }
+    }
+
+    async fn try_expect_message<P>(&self, predicate: &P, timeout: Duration) -> Option<UMessage>
+    where
+        P: Fn(&UMessage) -> bool,
+    {
+        tokio::time::timeout(timeout, async {
+            loop {
+                // register for notifications before checking the recording in order to not miss any messages
+                let mut message_sent = pin!(self.message_sent.notified());
+                message_sent.as_mut().enable();
+                if let Some(msg) = self.take_unmatched(predicate) {
+                    return msg;
+                }
+                message_sent.await;
+            }
+        })
+        .await
+        .ok()
+    }
+
+    fn take_unmatched<P>(&self, predicate: &P) -> Option<UMessage>
+    where
+        P: Fn(&UMessage) -> bool,
+    {
+        let mut recording = self.lock_recording();
+        let index = recording.unmatched.iter().position(predicate)?;
+        recording.unmatched.remove(index)
+    }
+
+    fn lock_recording(&self) -> MutexGuard<'_, Recording> {
+        self.recording
+            .lock()
+            .expect("failed to acquire lock for recording")
+    }
+
+    fn lock_listeners(&self) -> MutexGuard<'_, FilterIndex<ComparableListener>> {
+        self.listeners
+            .lock()
+            .expect("failed to acquire lock for listeners")
+    }
+}
+
+#[async_trait]
+impl UTransport for RecordingTransport {
+    /// Records a message.
+    ///
+    /// The message is not delivered to any listeners. Use [`RecordingTransport::inject`] for that purpose.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error that has been [set](RecordingTransport::set_send_error), if any.
+    async fn send(&self, message: UMessage) -> Result<(), UStatus> {
+        {
+            let mut recording = self.lock_recording();
+            if let Some(error) = recording.send_error.as_ref() {
+                return Err(error.to_owned());
+            }
+            recording.sent.push(message.clone());
+            recording.unmatched.push_back(message);
+        }
+        self.message_sent.notify_waiters();
+        Ok(())
+    }
+
+    async fn register_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        if self.lock_listeners().insert(
+            source_filter,
+            sink_filter,
+            ComparableListener::new(listener),
+        ) {
+            Ok(())
+        } else {
+            Err(UStatus::fail_with_code(
+                UCode::ALREADY_EXISTS,
+                "listener already registered for filters",
+            ))
+        }
+    }
+
+    async fn unregister_listener(
+        &self,
+        source_filter: &UUri,
+        sink_filter: Option<&UUri>,
+        listener: Arc<dyn UListener>,
+    ) -> Result<(), UStatus> {
+        self.lock_listeners()
+            .remove(
+                source_filter,
+                sink_filter,
+                &ComparableListener::new(listener),
+            )
+            .map(|_listener| ())
+            .ok_or_else(|| {
+                UStatus::fail_with_code(UCode::NOT_FOUND, "no such listener registered for filters")
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{utransport::MockUListener, UMessageBuilder};
+
+    fn topic() -> UUri {
+        UUri::try_from("//my-vehicle/A100/1/8001").unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_expectations_consume_matching_messages_in_order() {
+        let transport = Arc::new(RecordingTransport::default());
+        let first = UMessageBuilder::publish(topic()).build().unwrap();
+        let second = UMessageBuilder::publish(topic()).build().unwrap();
+        transport.send(first.clone()).await.unwrap();
+
+        // the second message is sent while waiting for it
+        let sender = transport.clone();
+        let expected = second.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            sender.send(expected).await.unwrap();
+        });
+
+        let timeout = Duration::from_secs(1);
+        assert_eq!(transport.expect_publish(&topic(), timeout).await, first);
+        assert_eq!(transport.expect_publish(&topic(), timeout).await, second);
+        transport
+            .expect_no_message(|msg| msg.is_publish(), Duration::from_millis(10))
+            .await;
+        assert_eq!(transport.sent_messages(), vec![first, second]);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "no matching message has been sent")]
+    async fn test_expectation_fails_if_no_matching_message_is_sent() {
+        let transport = RecordingTransport::default();
+        let method = UUri::try_from("//my-vehicle/A100/1/1").unwrap();
+        let reply_to = UUri::try_from("//my-vehicle/B100/1/0").unwrap();
+        let other_method = UUri::try_from("//my-vehicle/A100/1/2").unwrap();
+        let request = UMessageBuilder::request(other_method, reply_to, 5000)
+            .build()
+            .unwrap();
+        transport.send(request).await.unwrap();
+
+        transport
+            .expect_request(&method, Duration::from_millis(10))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_send_fails_with_configured_error() {
+        let transport = RecordingTransport::default();
+        transport.set_send_error(Some(UStatus::fail_with_code(
+            UCode::UNAVAILABLE,
+            "not connected",
+        )));
+        let msg = UMessageBuilder::publish(topic()).build().unwrap();
+        assert!(transport
+            .send(msg.clone())
+            .await
+            .is_err_and(|e| e.get_code() == UCode::UNAVAILABLE));
+        assert!(transport.sent_messages().is_empty());
+
+        transport.set_send_error(None);
+        assert!(transport.send(msg).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_inject_delivers_to_matching_listeners() {
+        let transport = RecordingTransport::default();
+        let mut listener = MockUListener::new();
+        listener.expect_on_receive().once().return_const(());
+        let listener = Arc::new(listener);
+        let other_topic = UUri::try_from("//my-vehicle/A100/1/8002").unwrap();
+        let mut other_listener = MockUListener::new();
+        other_listener.expect_on_receive().never();
+
+        transport
+            .register_listener(&topic(), None, listener.clone())
+            .await
+            .unwrap();
+        assert!(transport
+            .register_listener(&topic(), None, listener.clone())
+            .await
+            .is_err_and(|e| e.get_code() == UCode::ALREADY_EXISTS));
+        transport
+            .register_listener(&other_topic, None, Arc::new(other_listener))
+            .await
+            .unwrap();
+        assert!(transport.has_listener(&topic(), None));
+        assert_eq!(transport.listener_count(), 2);
+
+        let msg = UMessageBuilder::publish(topic()).build().unwrap();
+        assert_eq!(transport.inject(msg.clone()).await, 1);
+
+        transport
+            .unregister_listener(&topic(), None, listener.clone())
+            .await
+            .unwrap();
+        assert!(transport
+            .unregister_listener(&topic(), None, listener)
+            .await
+            .is_err_and(|e| e.get_code() == UCode::NOT_FOUND));
+        assert_eq!(transport.inject(msg).await, 0);
+    }
+}
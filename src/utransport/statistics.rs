@@ -0,0 +1,417 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::{UMessage, UMessageType, UPriority, UUri};
+
+/// The number of messages of a particular type and priority that have been processed by a transport.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MessageCounters {
+    /// The number of messages that have been accepted for sending.
+    pub sent: u64,
+    /// The number of times that messages have been delivered to a listener or returned from
+    /// [`UTransport::receive`](crate::UTransport::receive).
+    ///
+    /// A message that is delivered to multiple listeners is counted once per listener.
+    pub delivered: u64,
+    /// The number of times that messages have been discarded instead of being sent or delivered,
+    /// e.g. because they had expired or because a listener's queue was full.
+    pub dropped: u64,
+}
+
+impl MessageCounters {
+    fn add(&mut self, other: &MessageCounters) {
+        self.sent += other.sent;
+        self.delivered += other.delivered;
+        self.dropped += other.dropped;
+    }
+}
+
+/// The time that it took for messages to be delivered.
+///
+/// The latency of a message is the time that has passed between the creation of the message,
+/// as indicated by the timestamp contained in its [identifier](crate::UUID::get_time), and its
+/// delivery to a listener. Because the timestamp has millisecond precision, the latencies are
+/// in whole milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatencyStatistics {
+    /// The number of deliveries that the latency has been determined for.
+    pub count: u64,
+    /// The smallest latency.
+    pub min: Duration,
+    /// The largest latency.
+    pub max: Duration,
+    /// The sum of all latencies.
+    pub total: Duration,
+}
+
+impl LatencyStatistics {
+    /// Gets the average latency.
+    ///
+    /// # Returns
+    ///
+    /// `None` if no latency has been recorded yet.
+    pub fn mean(&self) -> Option<Duration> {
+        u32::try_from(self.count)
+            .ok()
+            .filter(|count| *count > 0)
+            .map(|count| self.total / count)
+    }
+
+    fn record(&mut self, latency: Duration) {
+        if self.count == 0 || latency < self.min {
+            self.min = latency;
+        }
+        if latency > self.max {
+            self.max = latency;
+        }
+        self.count += 1;
+        self.total += latency;
+    }
+}
+
+/// The source and sink filter patterns that listeners have been registered for.
+type ListenerFilter = (UUri, Option<UUri>);
+
+/// A snapshot of the statistics that a transport has collected about the messages it has processed
+/// and the listeners that are registered with it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TransportStatistics {
+    messages: HashMap<(UMessageType, UPriority), MessageCounters>,
+    listeners: HashMap<ListenerFilter, usize>,
+    delivery_latency: LatencyStatistics,
+}
+
+impl TransportStatistics {
+    /// Gets the counters for messages of a particular type and priority.
+    pub fn messages(&self, message_type: UMessageType, priority: UPriority) -> MessageCounters {
+        self.messages
+            .get(&(message_type, priority))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Gets the counters for all combinations of message type and priority that messages
+    /// have been processed for.
+    pub fn message_counters(
+        &self,
+    ) -> impl Iterator<Item = (UMessageType, UPriority, MessageCounters)> + '_ {
+        self.messages
+            .iter()
+            .map(|((message_type, priority), counters)| (*message_type, *priority, *counters))
+    }
+
+    /// Gets the counters for all messages, regardless of their type and priority.
+    pub fn total_messages(&self) -> MessageCounters {
+        self.messages
+            .values()
+            .fold(MessageCounters::default(), |mut total, counters| {
+                total.add(counters);
+                total
+            })
+    }
+
+    /// Gets the number of listeners that are registered for particular filter patterns.
+    pub fn listener_count(&self, source_filter: &UUri, sink_filter: Option<&UUri>) -> usize {
+        self.listeners
+            .get(&(source_filter.to_owned(), sink_filter.cloned()))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Gets the filter patterns that listeners are registered for, along with the
+    /// number of listeners per pattern.
+    pub fn listeners(&self) -> impl Iterator<Item = (&UUri, Option<&UUri>, usize)> {
+        self.listeners
+            .iter()
+            .map(|((source_filter, sink_filter), count)| {
+                (source_filter, sink_filter.as_ref(), *count)
+            })
+    }
+
+    /// Gets the number of all registered listeners.
+    pub fn total_listener_count(&self) -> usize {
+        self.listeners.values().sum()
+    }
+
+    /// Gets the time that it took for messages to be delivered to listeners.
+    pub fn delivery_latency(&self) -> LatencyStatistics {
+        self.delivery_latency
+    }
+}
+
+/// Collects [`TransportStatistics`] from the events reported by a transport.
+///
+/// This is a helper for implementing [`UTransport::statistics`](crate::UTransport::statistics).
+///
+/// # Examples
+///
+/// ```rust
+/// use up_rust::{StatisticsRecorder, UMessageBuilder, UMessageType, UPriority, UUri};
+///
+/// let recorder = StatisticsRecorder::default();
+/// let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+/// let message = UMessageBuilder::publish(topic.clone()).build().unwrap();
+///
+/// recorder.record_listener_registered(&topic, None);
+/// recorder.record_sent(&message);
+/// recorder.record_delivered(&message);
+///
+/// let statistics = recorder.snapshot();
+/// let counters = statistics.messages(UMessageType::UMESSAGE_TYPE_PUBLISH, UPriority::UPRIORITY_CS1);
+/// assert_eq!(counters.sent, 1);
+/// assert_eq!(counters.delivered, 1);
+/// assert_eq!(statistics.listener_count(&topic, None), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct StatisticsRecorder {
+    statistics: Mutex<TransportStatistics>,
+}
+
+impl StatisticsRecorder {
+    /// Records a message that has been accepted for sending.
+    pub fn record_sent(&self, message: &UMessage) {
+        self.update_counters(message, |counters| counters.sent += 1);
+    }
+
+    /// Records the delivery of a message to a listener, including the message's latency.
+    pub fn record_delivered(&self, message: &UMessage) {
+        let latency = message
+            .id()
+            .and_then(|id| id.get_time())
+            .and_then(|created| {
+                let now = crate::clock::now().duration_since(UNIX_EPOCH).ok()?;
+                now.checked_sub(Duration::from_millis(created))
+            });
+        if let Some(mut statistics) = self.lock_statistics() {
+            counters_for(&mut statistics, message).delivered += 1;
+            if let Some(latency) = latency {
+                statistics.delivery_latency.record(latency);
+            }
+        }
+    }
+
+    /// Records a message that has been discarded instead of being sent or delivered.
+    pub fn record_dropped(&self, message: &UMessage) {
+        self.update_counters(message, |counters| counters.dropped += 1);
+    }
+
+    /// Records the registration of a listener.
+    pub fn record_listener_registered(&self, source_filter: &UUri, sink_filter: Option<&UUri>) {
+        if let Some(mut statistics) = self.lock_statistics() {
+            *statistics
+                .listeners
+                .entry((source_filter.to_owned(), sink_filter.cloned()))
+                .or_default() += 1;
+        }
+    }
+
+    /// Records the removal of a listener.
+    pub fn record_listener_unregistered(&self, source_filter: &UUri, sink_filter: Option<&UUri>) {
+        if let Some(mut statistics) = self.lock_statistics() {
+            let filter = (source_filter.to_owned(), sink_filter.cloned());
+            if let Some(count) = statistics.listeners.get_mut(&filter) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    statistics.listeners.remove(&filter);
+                }
+            }
+        }
+    }
+
+    /// Gets the statistics collected so far.
+    pub fn snapshot(&self) -> TransportStatistics {
+        self.lock_statistics()
+            .map(|statistics| statistics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Resets the message counters and latencies.
+    ///
+    /// The numbers of registered listeners are kept.
+    pub fn reset(&self) {
+        if let Some(mut statistics) = self.lock_statistics() {
+            statistics.messages.clear();
+            statistics.delivery_latency = LatencyStatistics::default();
+        }
+    }
+
+    fn update_counters<F: FnOnce(&mut MessageCounters)>(&self, message: &UMessage, update: F) {
+        if let Some(mut statistics) = self.lock_statistics() {
+            update(counters_for(&mut statistics, message));
+        }
+    }
+
+    fn lock_statistics(&self) -> Option<MutexGuard<'_, TransportStatistics>> {
+        self.statistics.lock().ok()
+    }
+}
+
+fn counters_for<'a>(
+    statistics: &'a mut TransportStatistics,
+    message: &UMessage,
+) -> &'a mut MessageCounters {
+    let message_type = message
+        .type_()
+        .unwrap_or(UMessageType::UMESSAGE_TYPE_UNSPECIFIED);
+    let priority = message
+        .priority()
+        .unwrap_or(UPriority::UPRIORITY_UNSPECIFIED);
+    statistics
+        .messages
+        .entry((message_type, priority))
+        .or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::SystemTime;
+
+    use crate::{UMessageBuilder, UUID};
+
+    #[test]
+    fn test_recorder_tracks_messages_per_type_and_priority() {
+        let recorder = StatisticsRecorder::default();
+        let topic = UUri::try_from("//my-vehicle/A100/1/8001").unwrap();
+        let publish = UMessageBuilder::publish(topic.clone()).build().unwrap();
+        let notification =
+            UMessageBuilder::notification(topic, UUri::try_from("//my-vehicle/B100/1/0").unwrap())
+                .with_priority(UPriority::UPRIORITY_CS4)
+                .build()
+                .unwrap();
+
+        recorder.record_sent(&publish);
+        recorder.record_sent(&notification);
+        recorder.record_delivered(&publish);
+        recorder.record_delivered(&publish);
+        recorder.record_dropped(&notification);
+
+        let statistics = recorder.snapshot();
+        assert_eq!(
+            statistics.messages(
+                UMessageType::UMESSAGE_TYPE_PUBLISH,
+                UPriority::UPRIORITY_CS1
+            ),
+            MessageCounters {
+                sent: 1,
+                delivered: 2,
+                dropped: 0
+            }
+        );
+        assert_eq!(
+            statistics.messages(
+                UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                UPriority::UPRIORITY_CS4
+            ),
+            MessageCounters {
+                sent: 1,
+                delivered: 0,
+                dropped: 1
+            }
+        );
+        assert_eq!(
+            statistics.total_messages(),
+            MessageCounters {
+                sent: 2,
+                delivered: 2,
+                dropped: 1
+            }
+        );
+        let mut all_counters: Vec<_> = statistics.message_counters().collect();
+        all_counters.sort_by_key(|(message_type, _, _)| *message_type as i32);
+        assert_eq!(
+            all_counters,
+            vec![
+                (
+                    UMessageType::UMESSAGE_TYPE_PUBLISH,
+                    UPriority::UPRIORITY_CS1,
+                    MessageCounters {
+                        sent: 1,
+                        delivered: 2,
+                        dropped: 0
+                    }
+                ),
+                (
+                    UMessageType::UMESSAGE_TYPE_NOTIFICATION,
+                    UPriority::UPRIORITY_CS4,
+                    MessageCounters {
+                        sent: 1,
+                        delivered: 0,
+                        dropped: 1
+                    }
+                )
+            ]
+        );
+        assert_eq!(statistics.delivery_latency().count, 2);
+
+        recorder.reset();
+        assert_eq!(
+            recorder.snapshot().total_messages(),
+            MessageCounters::default()
+        );
+    }
+
+    #[test]
+    fn test_recorder_determines_latency_from_message_id() {
+        let recorder = StatisticsRecorder::default();
+        let created = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .checked_sub(Duration::from_millis(200))
+            .unwrap();
+        let message = UMessageBuilder::publish(UUri::try_from("//my-vehicle/A100/1/8001").unwrap())
+            .with_message_id(UUID::build_for_timestamp(created))
+            .build()
+            .unwrap();
+
+        recorder.record_delivered(&message);
+
+        let latency = recorder.snapshot().delivery_latency();
+        assert_eq!(latency.count, 1);
+        assert!(latency.min >= Duration::from_millis(200));
+        assert_eq!(latency.min, latency.max);
+        assert_eq!(latency.mean(), Some(latency.total));
+    }
+
+    #[test]
+    fn test_recorder_tracks_listeners_per_filter() {
+        let recorder = StatisticsRecorder::default();
+        let source_filter = UUri::any();
+        let sink_filter = UUri::try_from("//my-vehicle/B100/1/0").unwrap();
+
+        recorder.record_listener_registered(&source_filter, Some(&sink_filter));
+        recorder.record_listener_registered(&source_filter, Some(&sink_filter));
+        recorder.record_listener_registered(&source_filter, None);
+        recorder.record_listener_unregistered(&source_filter, None);
+        recorder.record_listener_unregistered(&source_filter, None);
+
+        let statistics = recorder.snapshot();
+        assert_eq!(
+            statistics.listener_count(&source_filter, Some(&sink_filter)),
+            2
+        );
+        assert_eq!(statistics.listener_count(&source_filter, None), 0);
+        assert_eq!(statistics.total_listener_count(), 2);
+        assert_eq!(
+            statistics.listeners().collect::<Vec<_>>(),
+            vec![(&source_filter, Some(&sink_filter), 2)]
+        );
+    }
+}
@@ -0,0 +1,414 @@
+/********************************************************************************
+ * Copyright (c) 2026 Contributors to the Eclipse Foundation
+ *
+ * See the NOTICE file(s) distributed with this work for additional
+ * information regarding copyright ownership.
+ *
+ * This program and the accompanying materials are made available under the
+ * terms of the Apache License Version 2.0 which is available at
+ * https://www.apache.org/licenses/LICENSE-2.0
+ *
+ * SPDX-License-Identifier: Apache-2.0
+ ********************************************************************************/
+
+#[cfg(feature = "config")]
+use std::path::Path;
+
+use crate::{StaticUriProvider, UUriError};
+
+/// The prefix of the environment variables used by [`StaticUriProvider::from_env`].
+const DEFAULT_ENV_PREFIX: &str = "UP";
+
+const AUTHORITY: &str = "authority";
+const ENTITY_ID: &str = "entity_id";
+const MAJOR_VERSION: &str = "major_version";
+
+/// An error indicating a problem with the configuration of a uEntity's identity.
+#[derive(Debug)]
+pub enum UriProviderConfigError {
+    /// A required setting has not been provided.
+    MissingSetting(String),
+    /// A setting's value cannot be parsed.
+    InvalidSetting { setting: String, reason: String },
+    /// The configuration file cannot be read.
+    Io(std::io::Error),
+    /// The configuration file's content cannot be parsed.
+    Parse(String),
+    /// The settings do not result in a valid uEntity URI.
+    InvalidUri(UUriError),
+}
+
+impl std::fmt::Display for UriProviderConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingSetting(setting) => {
+                f.write_fmt(format_args!("Missing setting: {}", setting))
+            }
+            Self::InvalidSetting { setting, reason } => {
+                f.write_fmt(format_args!("Invalid setting {}: {}", setting, reason))
+            }
+            Self::Io(e) => f.write_fmt(format_args!("Failed to read configuration: {}", e)),
+            Self::Parse(e) => f.write_fmt(format_args!("Failed to parse configuration: {}", e)),
+            Self::InvalidUri(e) => f.write_fmt(format_args!("Invalid uEntity URI: {}", e)),
+        }
+    }
+}
+
+impl std::error::Error for UriProviderConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::InvalidUri(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Parses an unsigned integer from its decimal or `0x` prefixed hexadecimal representation.
+fn parse_number<T>(setting: &str, value: &str) -> Result<T, UriProviderConfigError>
+where
+    T: TryFrom<u64>,
+{
+    let value = value.trim();
+    let number = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    }
+    .map_err(|e| UriProviderConfigError::InvalidSetting {
+        setting: setting.to_string(),
+        reason: format!("[{value}] is not a number: {e}"),
+    })?;
+    T::try_from(number).map_err(|_e| UriProviderConfigError::InvalidSetting {
+        setting: setting.to_string(),
+        reason: format!("[{value}] is out of range"),
+    })
+}
+
+/// Creates a URI provider for a uEntity, verifying that the resulting URI is a valid uEntity URI.
+fn create_provider(
+    authority: String,
+    entity_id: u32,
+    major_version: u8,
+) -> Result<StaticUriProvider, UriProviderConfigError> {
+    let provider = StaticUriProvider::new(authority, entity_id, major_version);
+    let source_uri = provider.local_uri.clone();
+    source_uri
+        .check_validity()
+        .and_then(|_| source_uri.verify_no_wildcards())
+        .map_err(UriProviderConfigError::InvalidUri)?;
+    Ok(provider)
+}
+
+/// Creates a URI provider from settings retrieved by means of a lookup function.
+fn from_settings<F>(lookup: F) -> Result<StaticUriProvider, UriProviderConfigError>
+where
+    F: Fn(&str) -> Result<Option<String>, UriProviderConfigError>,
+{
+    let get = |setting: &str| {
+        lookup(setting)?.ok_or_else(|| UriProviderConfigError::MissingSetting(setting.to_string()))
+    };
+    let authority = get(AUTHORITY)?;
+    let entity_id = parse_number(ENTITY_ID, &get(ENTITY_ID)?)?;
+    let major_version = parse_number(MAJOR_VERSION, &get(MAJOR_VERSION)?)?;
+    create_provider(authority, entity_id, major_version)
+}
+
+/// The content of an entity file.
+#[cfg(feature = "config")]
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct EntityFile {
+    authority: String,
+    entity_id: NumberSetting,
+    major_version: NumberSetting,
+}
+
+/// A numeric setting, which may also be given as a string containing a hexadecimal number.
+#[cfg(feature = "config")]
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum NumberSetting {
+    Number(u64),
+    Text(String),
+}
+
+#[cfg(feature = "config")]
+impl NumberSetting {
+    fn parse<T: TryFrom<u64>>(&self, setting: &str) -> Result<T, UriProviderConfigError> {
+        match self {
+            Self::Number(number) => parse_number(setting, &number.to_string()),
+            Self::Text(text) => parse_number(setting, text),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl TryFrom<EntityFile> for StaticUriProvider {
+    type Error = UriProviderConfigError;
+
+    fn try_from(entity: EntityFile) -> Result<Self, Self::Error> {
+        create_provider(
+            entity.authority,
+            entity.entity_id.parse(ENTITY_ID)?,
+            entity.major_version.parse(MAJOR_VERSION)?,
+        )
+    }
+}
+
+impl StaticUriProvider {
+    /// Creates a URI provider from environment variables.
+    ///
+    /// This is equivalent to invoking [`Self::from_env_with_prefix`] with prefix `UP`,
+    /// i.e. the uEntity's identity is read from variables `UP_AUTHORITY`, `UP_ENTITY_ID` and
+    /// `UP_MAJOR_VERSION`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the variables is missing or contains an invalid value.
+    pub fn from_env() -> Result<Self, UriProviderConfigError> {
+        Self::from_env_with_prefix(DEFAULT_ENV_PREFIX)
+    }
+
+    /// Creates a URI provider from environment variables.
+    ///
+    /// The uEntity's identity is read from the following variables:
+    ///
+    /// * `<prefix>_AUTHORITY` - The uEntity's authority name.
+    /// * `<prefix>_ENTITY_ID` - The entity identifier, either as a decimal or `0x` prefixed hexadecimal number.
+    /// * `<prefix>_MAJOR_VERSION` - The uEntity's major version, either as a decimal or `0x` prefixed hexadecimal number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the variables is missing or contains an invalid value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{LocalUriProvider, StaticUriProvider};
+    ///
+    /// std::env::set_var("MY_APP_AUTHORITY", "my-vehicle");
+    /// std::env::set_var("MY_APP_ENTITY_ID", "0x4210");
+    /// std::env::set_var("MY_APP_MAJOR_VERSION", "5");
+    /// let provider = StaticUriProvider::from_env_with_prefix("MY_APP").unwrap();
+    /// assert_eq!(provider.get_source_uri().ue_id, 0x4210);
+    /// ```
+    pub fn from_env_with_prefix(prefix: &str) -> Result<Self, UriProviderConfigError> {
+        from_settings(|setting| {
+            let variable = format!("{}_{}", prefix, setting.to_uppercase());
+            match std::env::var(&variable) {
+                Ok(value) => Ok(Some(value)),
+                Err(std::env::VarError::NotPresent) => Ok(None),
+                Err(e) => Err(UriProviderConfigError::InvalidSetting {
+                    setting: variable,
+                    reason: e.to_string(),
+                }),
+            }
+        })
+        .map_err(|e| match e {
+            // report the name of the environment variable instead of the setting
+            UriProviderConfigError::MissingSetting(setting) => {
+                UriProviderConfigError::MissingSetting(format!(
+                    "{}_{}",
+                    prefix,
+                    setting.to_uppercase()
+                ))
+            }
+            other => other,
+        })
+    }
+
+    /// Creates a URI provider from an entity file.
+    ///
+    /// The file's format is determined by its extension, which must be either `toml` or `json`.
+    /// The file needs to contain the `authority`, `entity_id` and `major_version` of the uEntity.
+    /// The numeric values may also be given as strings containing a `0x` prefixed hexadecimal number.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not contain a valid uEntity identity.
+    #[cfg(feature = "config")]
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, UriProviderConfigError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(UriProviderConfigError::Io)?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml_str(&content),
+            Some("json") => Self::from_json_str(&content),
+            _ => Err(UriProviderConfigError::Parse(format!(
+                "unsupported file format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Creates a URI provider from the TOML representation of an entity file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML document does not contain a valid uEntity identity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{LocalUriProvider, StaticUriProvider};
+    ///
+    /// let provider = StaticUriProvider::from_toml_str(
+    ///     r#"
+    ///     authority = "my-vehicle"
+    ///     entity_id = 0x4210
+    ///     major_version = 5
+    ///     "#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(provider.get_authority(), "my-vehicle");
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_toml_str(content: &str) -> Result<Self, UriProviderConfigError> {
+        toml::from_str::<EntityFile>(content)
+            .map_err(|e| UriProviderConfigError::Parse(e.to_string()))
+            .and_then(StaticUriProvider::try_from)
+    }
+
+    /// Creates a URI provider from the JSON representation of an entity file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON document does not contain a valid uEntity identity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use up_rust::{LocalUriProvider, StaticUriProvider};
+    ///
+    /// let provider = StaticUriProvider::from_json_str(
+    ///     r#"{ "authority": "my-vehicle", "entity_id": "0x4210", "major_version": 5 }"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(provider.get_source_uri().ue_id, 0x4210);
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn from_json_str(content: &str) -> Result<Self, UriProviderConfigError> {
+        serde_json::from_str::<EntityFile>(content)
+            .map_err(|e| UriProviderConfigError::Parse(e.to_string()))
+            .and_then(StaticUriProvider::try_from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use test_case::test_case;
+
+    use super::*;
+    use crate::LocalUriProvider;
+
+    fn provider_from_map(
+        settings: &[(&str, &str)],
+    ) -> Result<StaticUriProvider, UriProviderConfigError> {
+        let settings: HashMap<String, String> = settings
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        from_settings(|setting| Ok(settings.get(setting).cloned()))
+    }
+
+    #[test_case("16912", 0x4210; "decimal")]
+    #[test_case("0x4210", 0x4210; "hexadecimal")]
+    #[test_case(" 0X4210 ", 0x4210; "hexadecimal with whitespace")]
+    fn test_from_settings_succeeds(entity_id: &str, expected_entity_id: u32) {
+        let provider = provider_from_map(&[
+            (AUTHORITY, "my-vehicle"),
+            (ENTITY_ID, entity_id),
+            (MAJOR_VERSION, "5"),
+        ])
+        .unwrap();
+        let source_uri = provider.get_source_uri();
+        assert_eq!(source_uri.authority_name, "my-vehicle");
+        assert_eq!(source_uri.ue_id, expected_entity_id);
+        assert_eq!(source_uri.ue_version_major, 5);
+    }
+
+    #[test]
+    fn test_from_settings_fails_for_missing_setting() {
+        let result = provider_from_map(&[(AUTHORITY, "my-vehicle"), (ENTITY_ID, "0x4210")]);
+        assert!(result.is_err_and(
+            |e| matches!(e, UriProviderConfigError::MissingSetting(s) if s == MAJOR_VERSION)
+        ));
+    }
+
+    #[test_case("my-vehicle", "0x4210", "0x100"; "version out of range")]
+    #[test_case("my-vehicle", "abc", "1"; "entity ID not a number")]
+    #[test_case("my-vehicle", "0x1_0000_0000", "1"; "entity ID out of range")]
+    fn test_from_settings_fails_for_invalid_setting(
+        authority: &str,
+        entity_id: &str,
+        version: &str,
+    ) {
+        let result = provider_from_map(&[
+            (AUTHORITY, authority),
+            (ENTITY_ID, entity_id),
+            (MAJOR_VERSION, version),
+        ]);
+        assert!(result.is_err_and(|e| matches!(e, UriProviderConfigError::InvalidSetting { .. })));
+    }
+
+    #[test_case("my-vehicle", "0xFFFF", "1"; "wildcard entity type")]
+    #[test_case("*", "0x4210", "1"; "wildcard authority")]
+    #[test_case("my-vehicle:4711", "0x4210", "1"; "authority with port")]
+    fn test_from_settings_fails_for_invalid_uri(authority: &str, entity_id: &str, version: &str) {
+        let result = provider_from_map(&[
+            (AUTHORITY, authority),
+            (ENTITY_ID, entity_id),
+            (MAJOR_VERSION, version),
+        ]);
+        assert!(result.is_err_and(|e| matches!(e, UriProviderConfigError::InvalidUri(_))));
+    }
+
+    #[test]
+    fn test_from_env_with_prefix_reports_variable_name() {
+        let result = StaticUriProvider::from_env_with_prefix("UP_RUST_TEST_UNSET");
+        assert!(result.is_err_and(|e| matches!(
+            e,
+            UriProviderConfigError::MissingSetting(s) if s == "UP_RUST_TEST_UNSET_AUTHORITY"
+        )));
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn test_from_file_supports_toml_and_json() {
+        let dir = std::env::temp_dir();
+        let toml_file = dir.join(format!("up-rust-entity-{}.toml", std::process::id()));
+        std::fs::write(
+            &toml_file,
+            "authority = \"my-vehicle\"\nentity_id = 0x4210\nmajor_version = 5\n",
+        )
+        .unwrap();
+        let json_file = dir.join(format!("up-rust-entity-{}.json", std::process::id()));
+        std::fs::write(
+            &json_file,
+            r#"{"authority": "my-vehicle", "entity_id": "0x4210", "major_version": 5}"#,
+        )
+        .unwrap();
+
+        let from_toml = StaticUriProvider::from_file(&toml_file);
+        let from_json = StaticUriProvider::from_file(&json_file);
+        let _ = std::fs::remove_file(&toml_file);
+        let _ = std::fs::remove_file(&json_file);
+
+        let expected = StaticUriProvider::new("my-vehicle", 0x4210, 0x05).get_source_uri();
+        assert_eq!(from_toml.unwrap().get_source_uri(), expected);
+        assert_eq!(from_json.unwrap().get_source_uri(), expected);
+    }
+
+    #[cfg(feature = "config")]
+    #[test]
+    fn test_from_toml_str_rejects_unknown_fields() {
+        let result = StaticUriProvider::from_toml_str(
+            "authority = \"my-vehicle\"\nentity_id = 1\nmajor_version = 1\nversion = 1\n",
+        );
+        assert!(result.is_err_and(|e| matches!(e, UriProviderConfigError::Parse(_))));
+    }
+}
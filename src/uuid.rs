@@ -146,6 +146,11 @@ impl UUID {
 
     /// Creates a new UUID that can be used for uProtocol messages.
     ///
+    /// The UUID's timestamp is taken from the system clock. In tests that run a
+    /// `simulation::Simulation`, the simulation's virtual clock is used instead. The virtual clock
+    /// is only installed for the thread that has started the simulation, so UUIDs that are created
+    /// on other threads, e.g. by means of `tokio::task::spawn_blocking`, still use the system clock.
+    ///
     /// # Panics
     ///
     /// if the system clock is set to an instant before the UNIX Epoch.
@@ -161,8 +166,8 @@ impl UUID {
     // [impl->dsn~uuid-spec~1]
     // [utest->dsn~uuid-spec~1]
     pub fn build() -> UUID {
-        let duration_since_unix_epoch = SystemTime::UNIX_EPOCH
-            .elapsed()
+        let duration_since_unix_epoch = crate::clock::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
             .expect("current system time is set to a point in time before UNIX Epoch");
         Self::build_for_timestamp(duration_since_unix_epoch)
     }